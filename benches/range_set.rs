@@ -0,0 +1,131 @@
+//
+// Compare RangeSet's insert and remove against the previous implementation,
+// which pushed the new range, sorted the whole vector, and then compacted
+// it (for insert), or rebuilt the whole vector (for remove).
+//
+// Run with:
+//      cargo bench --bench range_set
+//
+use std::ops::Range;
+use aoc2022::RangeSet;
+use aoc2022::bench::{measure, Stats};
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+fn main() {
+    for size in SIZES {
+        let ranges = random_ranges(size, 0x2022);
+        let removals = random_ranges(size / 4, 0x1225);
+
+        // Make sure they agree before timing anything
+        let mut old = Vec::new();
+        let mut new = RangeSet::new();
+        for range in &ranges {
+            old_insert(&mut old, range.clone());
+            new.insert(range.clone());
+        }
+        for range in &removals {
+            old_remove(&mut old, range.clone());
+            new.remove(range.clone());
+        }
+        assert_eq!(old, new.ranges);
+
+        let old_stats = measure(1, 5, || {
+            let mut set = Vec::new();
+            for range in &ranges {
+                old_insert(&mut set, range.clone());
+            }
+            for range in &removals {
+                old_remove(&mut set, range.clone());
+            }
+        });
+        let new_stats = measure(1, 5, || {
+            let mut set = RangeSet::new();
+            for range in &ranges {
+                set.insert(range.clone());
+            }
+            for range in &removals {
+                set.remove(range.clone());
+            }
+        });
+
+        println!("{size} inserts, {} removes ({} ranges remain)", removals.len(), new.ranges.len());
+        report("  old", &old_stats);
+        report("  new", &new_stats);
+        println!("  speedup: {:.1}x", old_stats.median.as_secs_f64() / new_stats.median.as_secs_f64());
+    }
+}
+
+fn report(label: &str, stats: &Stats) {
+    println!("{label}: min {:.2?}  median {:.2?}  p95 {:.2?}", stats.min, stats.median, stats.p95);
+}
+
+//
+// Short ranges scattered over a wide span, so that most of them stay
+// disjoint and the set ends up with lots of ranges.  The generator is
+// a simple LCG, so runs are repeatable without any extra dependencies.
+//
+fn random_ranges(count: usize, seed: u64) -> Vec<Range<i64>> {
+    let mut state = seed;
+    let mut next = move || {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (state >> 33) as i64
+    };
+    let span = count as i64 * 100;
+    (0..count).map(|_| {
+        let start = next() % span;
+        let len = 1 + next() % 60;
+        start .. start + len
+    }).collect()
+}
+
+fn old_insert(ranges: &mut Vec<Range<i64>>, range: Range<i64>) {
+    if range.is_empty() {
+        return;
+    }
+
+    ranges.push(range);
+    ranges.sort_unstable_by_key(|r| r.start);
+
+    let mut head = 0;
+    let mut tail = 1;
+    while tail < ranges.len() {
+        if ranges[head].end < ranges[tail].start {
+            if head+1 < tail {
+                ranges[head+1] = ranges[tail].clone();
+            }
+            head += 1;
+            tail += 1;
+        } else {
+            ranges[head].end = ranges[head].end.max(ranges[tail].end);
+            tail += 1;
+        }
+    }
+
+    ranges.truncate(head+1);
+}
+
+fn old_remove(ranges: &mut Vec<Range<i64>>, removed: Range<i64>) {
+    if removed.is_empty() {
+        return;
+    }
+
+    *ranges = ranges.iter().flat_map(|r| {
+        if r.end <= removed.start || r.start >= removed.end {
+            return vec![r.clone()];
+        }
+        if removed.start <= r.start && removed.end >= r.end {
+            return vec![];
+        }
+        if r.start < removed.start && removed.end < r.end {
+            return vec![r.start .. removed.start, removed.end .. r.end];
+        }
+        let mut res = r.clone();
+        if removed.start <= res.start {
+            res.start = removed.end;
+        } else {
+            res.end = removed.start;
+        }
+        vec![res]
+    }).collect();
+}
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, fmt::Display, path::Path};
+use anyhow::Context;
+use serde::Deserialize;
+
+//
+// Expected answers for known puzzle inputs.
+//
+// Everybody gets a different puzzle input, with different answers, so
+// the answers are keyed by a hash of the input.  The manifest is a TOML
+// file that looks like:
+//
+//      [day01.0a1b2c3d4e5f6789]
+//      name = "mday64"
+//      part1 = "70374"
+//      part2 = "204610"
+//
+// The `name` is just a reminder of whose input it is.  Either part
+// may be missing if that answer isn't known yet.
+//
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers(HashMap<String, HashMap<String, Expected>>);
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Expected {
+    pub name: Option<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        Ok(toml::from_str(text)?)
+    }
+
+    // A missing manifest is the same as one without any answers.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn lookup(&self, day: u8, input: &str) -> Option<&Expected> {
+        self.0.get(&format!("day{day:02}"))?.get(&input_hash(input))
+    }
+
+    //
+    // Every day that has answers for this input.  Usually that is
+    // nothing, or just the day the input is for; anything else means
+    // the input has been filed under the wrong day.
+    //
+    pub fn identify(&self, input: &str) -> Vec<(u8, &Expected)> {
+        let hash = input_hash(input);
+        let mut found: Vec<(u8, &Expected)> = self.0.iter()
+            .filter_map(|(key, inputs)| {
+                let day = key.strip_prefix("day")?.parse::<u8>().ok()?;
+                Some((day, inputs.get(&hash)?))
+            })
+            .collect();
+        found.sort_by_key(|&(day, _)| day);
+        found
+    }
+
+    pub fn expected(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        let expected = self.lookup(day, input)?;
+        match part {
+            1 => expected.part1.as_deref(),
+            2 => expected.part2.as_deref(),
+            _ => None
+        }
+    }
+
+    pub fn check(&self, day: u8, input: &str, part: u8, answer: &str) -> Verdict {
+        match self.expected(day, input, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+//
+// Identify a puzzle input by its contents.  This is 64-bit FNV-1a,
+// which is simple, and stable across platforms and Rust versions
+// (unlike `std::hash`).
+//
+pub fn input_hash(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "\
+[day01.a430d84680aabd0b]
+name = \"example\"
+part1 = \"3\"
+";
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(input_hash("hello"), "a430d84680aabd0b");
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        assert_eq!(answers.check(1, "hello", 1, "3"), Verdict::Pass);
+        assert_eq!(answers.check(1, "hello", 1, "4"), Verdict::Fail { expected: "3".into() });
+        assert_eq!(answers.check(1, "hello", 2, "4"), Verdict::Unknown);
+        assert_eq!(answers.check(1, "goodbye", 1, "3"), Verdict::Unknown);
+        assert_eq!(answers.check(2, "hello", 1, "3"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_identify() {
+        let answers = Answers::parse(MANIFEST).unwrap();
+        let found = answers.identify("hello");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 1);
+        assert_eq!(found[0].1.name.as_deref(), Some("example"));
+        assert!(answers.identify("goodbye").is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::BTreeMap, path::Path, time::{Duration, Instant}};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+//
+// Summary statistics for repeated timings of the same code.
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    // Percentiles use the nearest-rank method, so every reported value
+    // is one of the actual samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort_unstable();
+        let rank = |percent: usize| {
+            let rank = (percent * samples.len()).div_ceil(100);
+            samples[rank.max(1) - 1]
+        };
+        Stats {
+            samples: samples.len(),
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+//
+// Call `f` `warmup` times without timing it (to warm up caches and the
+// allocator), then `iterations` more times, timing each call.
+//
+pub fn measure<F>(warmup: usize, iterations: usize, mut f: F) -> Stats
+where F: FnMut()
+{
+    for _ in 0..warmup {
+        f();
+    }
+    let samples = (0..iterations.max(1)).map(|_| {
+        let now = Instant::now();
+        f();
+        now.elapsed()
+    }).collect();
+    Stats::from_samples(samples)
+}
+
+//
+// Previously recorded timings, to compare new timings against.  It is
+// stored as TOML, keyed by "dayNN.partN".  The input hash is recorded
+// so that timings for different inputs aren't compared.
+//
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline(BTreeMap<String, BTreeMap<String, Recorded>>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recorded {
+    pub input: String,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Recorded {
+    pub fn new(input_hash: &str, stats: &Stats) -> Self {
+        Recorded {
+            input: input_hash.to_string(),
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+        }
+    }
+
+    //
+    // How much slower `stats` is than this baseline, as a fraction of the
+    // baseline's median (0.25 means 25% slower).  Negative is faster.
+    //
+    pub fn slowdown(&self, stats: &Stats) -> f64 {
+        let old = self.median_ns as f64;
+        let new = stats.median.as_nanos() as f64;
+        (new - old) / old.max(1.0)
+    }
+}
+
+impl Baseline {
+    // A missing baseline is the same as an empty one.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let text = toml::to_string(self)?;
+        std::fs::write(path, text)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Recorded> {
+        self.0.get(&format!("day{day:02}"))?.get(&format!("part{part}"))
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, recorded: Recorded) {
+        self.0.entry(format!("day{day:02}"))
+            .or_default()
+            .insert(format!("part{part}"), recorded);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(v: u64) -> Duration {
+        Duration::from_millis(v)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples((1..=20).rev().map(ms).collect());
+        assert_eq!(stats.samples, 20);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(10));
+        assert_eq!(stats.p95, ms(19));
+
+        let stats = Stats::from_samples(vec![ms(7)]);
+        assert_eq!((stats.min, stats.median, stats.p95), (ms(7), ms(7), ms(7)));
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = Stats::from_samples(vec![ms(1), ms(2), ms(3)]);
+        let mut baseline = Baseline::default();
+        baseline.insert(7, 2, Recorded::new("0123456789abcdef", &stats));
+        let text = toml::to_string(&baseline).unwrap();
+        let baseline: Baseline = toml::from_str(&text).unwrap();
+        let recorded = baseline.get(7, 2).unwrap();
+        assert_eq!(recorded.median_ns, 2_000_000);
+        assert!(baseline.get(7, 1).is_none());
+
+        let slower = Stats::from_samples(vec![ms(3)]);
+        assert_eq!(recorded.slowdown(&slower), 0.5);
+    }
+}
@@ -0,0 +1,867 @@
+use std::{collections::BTreeMap, path::{Path, PathBuf}, str::FromStr, time::Instant};
+use anyhow::{anyhow, bail, Context};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use aoc2022::{default_input_path, solution, solutions, Params, Solution, Streamed};
+use aoc2022::answers::{input_hash, Answers, Verdict};
+use aoc2022::bench::{measure, Baseline, Recorded};
+use aoc2022::generate::generate;
+use aoc2022::inputs::{InputStore, Profile, PROFILE_ENV};
+use aoc2022::visualize::{Animation, FrameFormat};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one day, or for all of them
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Expected answers to check against
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Run with every profile's inputs from the input store
+        #[arg(long, conflicts_with_all = ["input", "profile"])]
+        all_profiles: bool,
+    },
+
+    /// Time the solutions, and compare against previous timings
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Number of timed runs of each part
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Number of untimed runs of each part before timing
+        #[arg(long, default_value_t = 2)]
+        warmup: usize,
+
+        /// Previous timings to compare against
+        #[arg(long, default_value = "bench_baseline.toml")]
+        baseline: PathBuf,
+
+        /// Record these timings in the baseline file
+        #[arg(long)]
+        save: bool,
+
+        /// Flag a regression if the median is this much slower (0.1 = 10%)
+        #[arg(long, default_value_t = 0.1)]
+        threshold: f64,
+    },
+
+    /// Animate a simulation day in the terminal, or save its frames
+    Viz {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Frames per second in the terminal
+        #[arg(long, default_value_t = 10.0)]
+        fps: f64,
+
+        /// Write numbered frames to this directory instead (a subdirectory per part)
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// Format of the frames written to --out
+        #[arg(long, value_enum, default_value_t = Frames::Txt)]
+        frames: Frames,
+
+        /// Pixels per cell in PPM frames
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+
+        /// Only show every Nth step (the final state is always shown)
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+    },
+
+    /// Report on a day's input, beyond its answers (only some days)
+    Analyze {
+        #[command(flatten)]
+        selection: Selection,
+    },
+
+    /// Write a random puzzle input for a day
+    Gen {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Seed for the random numbers; the same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big an input to make (what it counts depends on the day)
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+
+        /// Make the input for a puzzle parameter, like `--param bound=20` (see `aoc params`)
+        #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+
+        /// Write the input to this file instead of standard output
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+
+    /// List the puzzle parameters that can be changed with --param
+    Params {
+        /// Day number (1-25), or "all"
+        #[arg(default_value = "all")]
+        day: Days,
+    },
+
+    /// List the alternate strategies that can be chosen with --strategy
+    Strategies {
+        /// Day number (1-25), or "all"
+        #[arg(default_value = "all")]
+        day: Days,
+    },
+
+    /// Manage the puzzle inputs in the input store (inputs/<profile>/dayNN.txt)
+    Input {
+        #[command(subcommand)]
+        command: InputCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum InputCommand {
+    /// List the stored inputs, and whether answers.toml knows them
+    List {
+        /// Only list this profile's inputs
+        #[arg(long)]
+        profile: Option<Profile>,
+
+        /// Expected answers to check against
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+
+    /// Copy a puzzle input into the store
+    Add {
+        /// Day number (1-25)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// File to copy, or "-" for stdin
+        file: PathBuf,
+
+        /// Profile to add it to [default: $AOC_PROFILE]
+        #[arg(long)]
+        profile: Option<Profile>,
+
+        /// Replace an existing input
+        #[arg(long)]
+        replace: bool,
+
+        /// Expected answers to check against
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+
+    /// Check that every stored input is in answers.toml, under the right day
+    Verify {
+        /// Only verify this profile's inputs
+        #[arg(long)]
+        profile: Option<Profile>,
+
+        /// Expected answers to check against
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+}
+
+//
+// Which days and parts to run, and with what input
+//
+#[derive(Args)]
+struct Selection {
+    /// Day number (1-25), or "all"
+    day: Days,
+
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input, or "-" for stdin [default: the profile's input, or src/days/dayNN/input.txt]
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// Use this profile's inputs from the input store [default: $AOC_PROFILE]
+    #[arg(long)]
+    profile: Option<Profile>,
+
+    /// Change a puzzle parameter, like `--param row=10` (see `aoc params`)
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Solve with this strategy, or "all" to run every strategy and check
+    /// that they agree (see `aoc strategies`)
+    #[arg(long)]
+    strategy: Option<String>,
+}
+
+//
+// One day to run, and where its input comes from
+//
+struct Job {
+    solution: &'static dyn Solution,
+    path: PathBuf,
+    profile: Option<Profile>,
+}
+
+impl Selection {
+    fn selected(&self) -> anyhow::Result<Vec<&'static dyn Solution>> {
+        if matches!(self.day, Days::All) && self.input.is_some() {
+            bail!("--input needs a single day");
+        }
+        let selected = self.day.solutions()?;
+
+        // Catch misspelled parameters before running anything
+        for (name, _) in &self.params {
+            let declared = |solution: &&dyn Solution| solution.params().iter().any(|param| param.name == name);
+            if matches!(self.day, Days::All) && !selected.iter().any(declared) {
+                bail!("no day has a parameter named {name:?}");
+            }
+        }
+        for &solution in &selected {
+            self.params(solution)?;
+        }
+
+        if let Some(name) = self.strategy.as_deref().filter(|&name| name != "all") {
+            let parts = |solution: &&dyn Solution| self.parts(*solution).collect::<Vec<_>>();
+            let found = selected.iter().any(|solution| {
+                solution.strategies().iter().any(|strategy| strategy.name == name && parts(solution).contains(&strategy.part))
+            });
+            if !found {
+                bail!("no strategy named {name:?} for the selected parts; see `aoc strategies`");
+            }
+        }
+        Ok(selected)
+    }
+
+    //
+    // The strategies to run for a part, by name.  `None` is the part's
+    // usual solution.  Parts without the named strategy run as usual,
+    // and "all" skips slow strategies.
+    //
+    fn strategies(&self, solution: &dyn Solution, part: u8) -> Vec<Option<&str>> {
+        let strategies = solution.strategies_for(part);
+        match self.strategy.as_deref() {
+            Some("all") if !strategies.is_empty() => {
+                strategies.iter().filter(|strategy| !strategy.slow).map(|strategy| Some(strategy.name)).collect()
+            }
+            Some(name) if strategies.iter().any(|strategy| strategy.name == name) => vec![Some(name)],
+            _ => vec![None],
+        }
+    }
+
+    //
+    // The day's default parameters, changed by --param.  With "all",
+    // each day only gets the parameters it declares.
+    //
+    fn params(&self, solution: &dyn Solution) -> anyhow::Result<Params> {
+        let declared = solution.params();
+        let given = self.params.iter()
+            .filter(|(name, _)| matches!(self.day, Days::One(_)) || declared.iter().any(|param| param.name == name))
+            .map(|(name, value)| (name, value.clone()));
+        Params::new(declared, given).with_context(|| format!("Day {}", solution.day()))
+    }
+
+    //
+    // Where the input comes from, in order of preference: --input,
+    // --profile, $AOC_PROFILE, or the input that lives next to the
+    // day's solution.
+    //
+    fn solutions(&self) -> anyhow::Result<Vec<Job>> {
+        let profile = match &self.profile {
+            Some(profile) => Some(profile.clone()),
+            None => Profile::from_env()?,
+        };
+        let store = InputStore::default();
+        Ok(self.selected()?.into_iter().map(|solution| {
+            let day = solution.day();
+            match (&self.input, &profile) {
+                (Some(path), _) => Job { solution, path: path.clone(), profile: None },
+                (None, Some(profile)) => {
+                    Job { solution, path: store.path(profile, day), profile: Some(profile.clone()) }
+                }
+                (None, None) => Job { solution, path: default_input_path(day).into(), profile: None },
+            }
+        }).collect())
+    }
+
+    // The selected days, for every profile in the store that has them.
+    fn all_profiles(&self) -> anyhow::Result<Vec<Job>> {
+        let store = InputStore::default();
+        let selected = self.selected()?;
+        let mut jobs = Vec::new();
+        for profile in store.profiles()? {
+            let days = store.days(&profile)?;
+            for &solution in selected.iter().filter(|solution| days.contains(&solution.day())) {
+                let path = store.path(&profile, solution.day());
+                jobs.push(Job { solution, path, profile: Some(profile.clone()) });
+            }
+        }
+        if jobs.is_empty() {
+            bail!("no inputs for those days in {}", store.root().display());
+        }
+        Ok(jobs)
+    }
+
+    fn parts(&self, solution: &dyn Solution) -> std::ops::RangeInclusive<u8> {
+        match self.part {
+            Some(part) => part..=part,
+            None => 1..=solution.parts(),
+        }
+    }
+}
+
+// "row=10" => ("row", "10")
+fn parse_param(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.is_empty() => Ok((name.to_string(), value.to_string())),
+        _ => Err(format!("expected NAME=VALUE; got {s:?}"))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Frames {
+    /// Plain text, as drawn in the terminal
+    Txt,
+    /// PPM images
+    Ppm,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Human readable
+    Text,
+    /// One JSON object per line, per part
+    Json,
+}
+
+//
+// One line of `--format json` output
+//
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strategy: Option<&'a str>,
+    answer: &'a str,
+    elapsed_ns: u64,
+    input: String,
+    params: BTreeMap<&'a str, &'a str>,
+    verdict: String,
+}
+
+#[derive(Clone, Copy)]
+enum Days {
+    All,
+    One(u8),
+}
+
+impl Days {
+    fn solutions(self) -> anyhow::Result<Vec<&'static dyn Solution>> {
+        Ok(match self {
+            Days::All => solutions().to_vec(),
+            Days::One(day) => vec![solution(day).ok_or_else(|| anyhow!("Day {day} is not solved yet"))?],
+        })
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Days::All);
+        }
+        match s.parse::<u8>() {
+            Ok(day @ 1..=25) => Ok(Days::One(day)),
+            _ => Err(format!("expected a day from 1 to 25, or \"all\"; got {s:?}"))
+        }
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    // The slow days log their searches (see `aoc2022::search`)
+    env_logger::init();
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { selection, answers, format, all_profiles } => {
+            let answers = Answers::load(&answers)?;
+            let jobs = if all_profiles {
+                selection.all_profiles()?
+            } else {
+                selection.solutions()?
+            };
+            run(&jobs, &selection, &answers, format)
+        }
+        Command::Bench { selection, iterations, warmup, baseline, save, threshold } => {
+            bench(&selection, iterations, warmup, &baseline, save, threshold)
+        }
+        Command::Viz { selection, fps, out, frames, scale, every } => {
+            viz(&selection, fps, out.as_deref(), frames, scale, every)
+        }
+        Command::Analyze { selection } => analyze(&selection),
+        Command::Gen { day, seed, size, params, out } => {
+            let solution = solution(day).ok_or_else(|| anyhow!("no solution for day {day}"))?;
+            let params = Params::new(solution.params(), params).with_context(|| format!("Day {day}"))?;
+            let input = generate(day, seed, size, &params)?;
+            match out {
+                Some(out) => std::fs::write(&out, input)
+                    .with_context(|| format!("writing {}", out.display())),
+                None => {
+                    print!("{input}");
+                    Ok(())
+                }
+            }
+        }
+        Command::Params { day } => {
+            list_params(day)
+        }
+        Command::Strategies { day } => {
+            list_strategies(day)
+        }
+        Command::Input { command } => {
+            let store = InputStore::default();
+            match command {
+                InputCommand::List { profile, answers } => {
+                    input_list(&store, profile, &Answers::load(&answers)?)
+                }
+                InputCommand::Add { day, file, profile, replace, answers } => {
+                    let profile = match profile {
+                        Some(profile) => profile,
+                        None => Profile::from_env()?
+                            .ok_or_else(|| anyhow!("which profile?  Use --profile or set {PROFILE_ENV}"))?,
+                    };
+                    input_add(&store, &profile, day, &file, replace, &Answers::load(&answers)?)
+                }
+                InputCommand::Verify { profile, answers } => {
+                    input_verify(&store, profile, &Answers::load(&answers)?)
+                }
+            }
+        }
+    }
+}
+
+// "-" is stdin
+fn read_input(path: &Path) -> anyhow::Result<String> {
+    if path == Path::new("-") {
+        return std::io::read_to_string(std::io::stdin()).context("reading stdin");
+    }
+    std::fs::read_to_string(path)
+        .with_context(|| format!("reading {}", path.display()))
+}
+
+fn run(jobs: &[Job], selection: &Selection, answers: &Answers, format: Format) -> anyhow::Result<()> {
+    let mut failures = 0;
+    for (i, job) in jobs.iter().enumerate() {
+        if i > 0 && format == Format::Text {
+            println!();
+        }
+        match run_day(job, selection, answers, format) {
+            Ok(true) => {},
+            Ok(false) => failures += 1,
+            Err(error) => {
+                // Keep stdout clean for JSON consumers
+                match format {
+                    Format::Text => println!("Error: {error:#}"),
+                    Format::Json => eprintln!("Error: {error:#}"),
+                }
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} day(s) failed");
+    }
+    Ok(())
+}
+
+//
+// Run the selected parts for one day, and check the answers.
+// Returns Ok(false) if any answer was wrong.
+//
+fn run_day(job: &Job, selection: &Selection, answers: &Answers, format: Format) -> anyhow::Result<bool> {
+    let &Job { solution, ref path, ref profile } = job;
+    let day = solution.day();
+    if format == Format::Text {
+        match profile {
+            Some(profile) => println!("== Day {day:02} ({profile}) =="),
+            None => println!("== Day {day:02} =="),
+        }
+    }
+
+    if let Some(profile) = profile {
+        if !path.exists() {
+            bail!("{profile} has no input for day {day}; add it with `aoc input add {day} <file> --profile {profile}`");
+        }
+    }
+    let params = selection.params(solution)?;
+
+    // Days that can, read stdin as it comes, rather than all at once.
+    // Then there is no input to look up in answers.toml.
+    let mut streamed = None;
+    if path == Path::new("-") && selection.strategy.is_none() {
+        let now = Instant::now();
+        if let Some(found) = solution.stream(&mut std::io::stdin().lock(), &params) {
+            streamed = Some((found.with_context(|| format!("Day {day}"))?, now.elapsed()));
+        }
+    }
+    let input = match streamed {
+        Some(_) => String::new(),
+        None => read_input(path)?,
+    };
+
+    // answers.toml only has answers for the default parameters
+    let check = streamed.is_none() && params == Params::defaults(solution.params());
+
+    let mut all_correct = true;
+    let mut any_unknown = false;
+    for part in selection.parts(solution) {
+        let mut first_answer = None;
+        for strategy in selection.strategies(solution, part) {
+            let (answer, duration) = match &streamed {
+                // The time is for all the parts together
+                Some((found, duration)) => (found.answers[part as usize - 1].clone(), *duration),
+                None => {
+                    let now = Instant::now();
+                    let answer = match strategy {
+                        None => solution.solve(part, &input, &params),
+                        Some(name) => solution.solve_with(part, name, &input, &params),
+                    }.with_context(|| format!("Day {day} part {part}"))?;
+                    (answer, now.elapsed())
+                }
+            };
+            let verdict = match check {
+                true => answers.check(day, &input, part, &answer),
+                false => Verdict::Unknown,
+            };
+            match format {
+                Format::Text => {
+                    let label = match strategy {
+                        Some(name) => format!("Part {part} ({name})"),
+                        None => format!("Part {part}"),
+                    };
+                    if answer.contains('\n') {
+                        // Multi-line answers (like a CRT screen) go below the label
+                        println!("{label}: [{verdict}] (in {duration:?})\n{}", answer.trim_end());
+                    } else {
+                        println!("{label}: {answer} [{verdict}] (in {duration:?})");
+                    }
+                    if let Verdict::Fail { expected } = &verdict {
+                        println!("    expected: {expected}");
+                    }
+                }
+                Format::Json => {
+                    let record = Record {
+                        day,
+                        part,
+                        strategy,
+                        answer: &answer,
+                        elapsed_ns: duration.as_nanos() as u64,
+                        input: path.display().to_string(),
+                        params: params.iter().collect(),
+                        verdict: verdict.to_string(),
+                    };
+                    println!("{}", serde_json::to_string(&record)?);
+                }
+            }
+            match verdict {
+                Verdict::Pass => {},
+                Verdict::Fail { .. } => all_correct = false,
+                Verdict::Unknown => any_unknown = true,
+            }
+
+            // With --strategy all, every strategy should get the same answer
+            match &first_answer {
+                None => first_answer = Some(answer),
+                Some(first) if *first != answer => {
+                    all_correct = false;
+                    if format == Format::Text {
+                        println!("    strategies disagree: {} != {}", first.trim_end(), answer.trim_end());
+                    }
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    if let Some((Streamed { report: Some(report), .. }, _)) = &streamed {
+        if format == Format::Text {
+            println!("{}", report.trim_end());
+        }
+    }
+    if any_unknown && format == Format::Text {
+        if streamed.is_some() {
+            println!("(Not checked, because the input was streamed)");
+        } else if check {
+            println!("(No expected answer for input hash {})", input_hash(&input));
+        } else {
+            println!("(Not checked, because the parameters were changed)");
+        }
+    }
+
+    Ok(all_correct)
+}
+
+//
+// Time each selected part, and compare against the baseline.  Timings
+// are only compared if the baseline was recorded with the same input.
+//
+fn bench(selection: &Selection, iterations: usize, warmup: usize, baseline_path: &Path, save: bool, threshold: f64) -> anyhow::Result<()> {
+    let mut baseline = Baseline::load(baseline_path)?;
+    let mut regressions = 0;
+
+    println!("{:<14} {:>12} {:>12} {:>12}", "", "min", "median", "p95");
+    for Job { solution, path, .. } in selection.solutions()? {
+        let day = solution.day();
+        let input = read_input(&path)?;
+        let hash = input_hash(&input);
+        let params = selection.params(solution)?;
+
+        // The baseline is only for the default parameters
+        let compare = params == Params::defaults(solution.params());
+        if save && !compare {
+            bail!("can't --save timings with --param");
+        }
+        if save && selection.strategy.is_some() {
+            bail!("can't --save timings with --strategy");
+        }
+        for (part, strategy) in selection.parts(solution).flat_map(|part| {
+            selection.strategies(solution, part).into_iter().map(move |strategy| (part, strategy))
+        }) {
+            let solve = || match strategy {
+                None => solution.solve(part, &input, &params),
+                Some(name) => solution.solve_with(part, name, &input, &params),
+            };
+
+            // Make sure it works at all before timing it
+            solve().with_context(|| format!("Day {day} part {part}"))?;
+
+            let stats = measure(warmup, iterations, || {
+                let _ = solve();
+            });
+
+            // The baseline is for each part's usual solution
+            let comparison = match baseline.get(day, part) {
+                Some(old) if compare && strategy.is_none() && old.input == hash => {
+                    let slowdown = old.slowdown(&stats);
+                    if slowdown > threshold {
+                        regressions += 1;
+                        format!("{:+.1}% REGRESSION", slowdown * 100.0)
+                    } else {
+                        format!("{:+.1}%", slowdown * 100.0)
+                    }
+                }
+                _ => String::new()
+            };
+            let comparison = match strategy {
+                Some(name) => format!("({name}) {comparison}").trim_end().to_string(),
+                None => comparison,
+            };
+            println!("Day {day:02} part {part} {:>12} {:>12} {:>12}  {comparison}",
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95));
+
+            if save && strategy.is_none() {
+                baseline.insert(day, part, Recorded::new(&hash, &stats));
+            }
+        }
+    }
+
+    if save {
+        baseline.save(baseline_path)?;
+    }
+    if regressions > 0 {
+        bail!("{regressions} part(s) slower than the baseline");
+    }
+    Ok(())
+}
+
+//
+// Run each selected part's simulation, drawing every step.
+//
+fn viz(selection: &Selection, fps: f64, out: Option<&Path>, frames: Frames, scale: usize, every: usize) -> anyhow::Result<()> {
+    if matches!(selection.day, Days::All) {
+        bail!("viz needs a single day");
+    }
+    if selection.strategy.is_some() {
+        bail!("viz doesn't use --strategy");
+    }
+    for Job { solution, path, .. } in selection.solutions()? {
+        let day = solution.day();
+        let input = read_input(&path)?;
+        let params = selection.params(solution)?;
+        for part in selection.parts(solution) {
+            let animation = match out {
+                Some(out) => {
+                    let format = match frames {
+                        Frames::Txt => FrameFormat::Text,
+                        Frames::Ppm => FrameFormat::Ppm,
+                    };
+                    Animation::directory(out.join(format!("part{part}")), format, scale)?
+                }
+                None => Animation::terminal(fps)?,
+            };
+            let mut animation = animation.every(every);
+            solution.visualize(part, &input, &params, &mut animation)?;
+            let count = animation.finish().with_context(|| format!("Day {day} part {part}"))?;
+            match out {
+                Some(out) => println!("Day {day} part {part}: {count} frames in {}", out.join(format!("part{part}")).display()),
+                None => println!("Day {day} part {part}: {count} frames"),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn analyze(selection: &Selection) -> anyhow::Result<()> {
+    if matches!(selection.day, Days::All) {
+        bail!("analyze needs a single day");
+    }
+    if selection.strategy.is_some() {
+        bail!("analyze doesn't use --strategy");
+    }
+    for Job { solution, path, .. } in selection.solutions()? {
+        let input = read_input(&path)?;
+        let params = selection.params(solution)?;
+        let report = solution.analyze(&input, &params)?;
+        print!("{report}");
+    }
+    Ok(())
+}
+
+fn list_params(day: Days) -> anyhow::Result<()> {
+    for solution in day.solutions()?.into_iter().filter(|solution| !solution.params().is_empty()) {
+        println!("Day {:02}", solution.day());
+        for param in solution.params() {
+            println!("  {:<14} {:>10}  {}", param.name, param.default, param.help);
+        }
+    }
+    Ok(())
+}
+
+// The first strategy for each part is the one that is normally used.
+fn list_strategies(day: Days) -> anyhow::Result<()> {
+    for solution in day.solutions()?.into_iter().filter(|solution| !solution.strategies().is_empty()) {
+        println!("Day {:02}", solution.day());
+        for part in 1..=solution.parts() {
+            for (i, strategy) in solution.strategies_for(part).into_iter().enumerate() {
+                let note = match (i, strategy.slow) {
+                    (0, _) => " (default)",
+                    (_, true) => " (slow)",
+                    _ => "",
+                };
+                println!("  part {part}  {:<16} {}{note}", strategy.name, strategy.help);
+            }
+        }
+    }
+    Ok(())
+}
+
+//
+// Print each stored input, with its hash and whether there are
+// expected answers for it.
+//
+fn input_list(store: &InputStore, profile: Option<Profile>, answers: &Answers) -> anyhow::Result<()> {
+    let profiles = match profile {
+        Some(profile) => vec![profile],
+        None => store.profiles()?,
+    };
+    if profiles.is_empty() {
+        println!("No inputs in {}", store.root().display());
+    }
+    for profile in profiles {
+        println!("{profile}");
+        for day in store.days(&profile)? {
+            let input = read_input(&store.path(&profile, day))?;
+            let known = match answers.lookup(day, &input) {
+                Some(expected) => {
+                    let parts = [("part1", &expected.part1), ("part2", &expected.part2)]
+                        .iter()
+                        .filter(|(_, answer)| answer.is_some())
+                        .map(|(part, _)| *part)
+                        .collect::<Vec<_>>();
+                    format!("answers for {}", parts.join(", "))
+                }
+                None => "no answers".to_string(),
+            };
+            println!("  day{day:02}  {}  {known}", input_hash(&input));
+        }
+    }
+    Ok(())
+}
+
+fn input_add(store: &InputStore, profile: &Profile, day: u8, file: &Path, replace: bool, answers: &Answers) -> anyhow::Result<()> {
+    let input = read_input(file)?;
+    let path = store.add(profile, day, &input, replace)?;
+    let hash = input_hash(&input);
+    match answers.identify(&input).first() {
+        Some((known_day, _)) if *known_day != day => {
+            println!("Added {} ({hash}), but answers.toml has it as day {known_day}'s input", path.display());
+        }
+        Some(_) => println!("Added {} ({hash}); answers.toml knows it", path.display()),
+        None => println!("Added {} ({hash}); add its answers to answers.toml as [day{day:02}.{hash}]", path.display()),
+    }
+    Ok(())
+}
+
+//
+// Check that every stored input has answers under its own day.  An
+// input that isn't in the manifest may be truncated or have different
+// line endings; one that is under another day has been misfiled.
+//
+fn input_verify(store: &InputStore, profile: Option<Profile>, answers: &Answers) -> anyhow::Result<()> {
+    let profiles = match profile {
+        Some(profile) => vec![profile],
+        None => store.profiles()?,
+    };
+    let mut checked = 0;
+    let mut problems = 0;
+    for profile in profiles {
+        for day in store.days(&profile)? {
+            let path = store.path(&profile, day);
+            let input = read_input(&path)?;
+            checked += 1;
+            if answers.lookup(day, &input).is_some() {
+                continue;
+            }
+            problems += 1;
+            let found = answers.identify(&input);
+            if let Some((other_day, _)) = found.first() {
+                println!("{}: answers.toml has this as day {other_day}'s input", path.display());
+            } else if input.contains('\r') {
+                println!("{}: not in answers.toml (it has CRLF line endings)", path.display());
+            } else {
+                println!("{}: not in answers.toml (hash {})", path.display(), input_hash(&input));
+            }
+        }
+    }
+
+    if problems > 0 {
+        bail!("{problems} of {checked} input(s) didn't match answers.toml");
+    }
+    println!("All {checked} input(s) match answers.toml");
+    Ok(())
+}
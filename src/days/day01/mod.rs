@@ -0,0 +1,176 @@
+use std::io::BufRead;
+use log::{debug, trace};
+use crate::{Param, Params, Rng, Solution, Streamed, TopK};
+use crate::parse::Line;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(Calories::read(input.as_bytes(), 1)?.max.to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(Calories::read(input.as_bytes(), params.get("top")?)?.top_total().to_string())
+    }
+
+    // The statistics, and which elves carry the most
+    fn analyze(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(Calories::read(input.as_bytes(), params.get("top")?)?.report())
+    }
+
+    // Both parts at once, reading a line at a time, with the report
+    // `analyze` would give
+    fn stream(&self, input: &mut dyn BufRead, params: &Params) -> Option<anyhow::Result<Streamed>> {
+        let calories = params.get("top").and_then(|top| Calories::read(input, top));
+        Some(calories.map(|calories| Streamed {
+            answers: vec![calories.max.to_string(), calories.top_total().to_string()],
+            report: Some(calories.report()),
+        }))
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "top", default: "3", help: "Number of elves carrying the most calories to add up (part 2)" },
+        ]
+    }
+
+    // `size` elves, each carrying a few snacks
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let elves = (0..size.max(1)).map(|_| {
+            let snacks = rng.range(1..=15);
+            (0..snacks).map(|_| format!("{}\n", rng.range(1000..=60000))).collect::<String>()
+        }).collect::<Vec<_>>();
+        Ok(elves.join("\n"))
+    }
+}
+
+//
+// Statistics about the elves, kept up to date as the input is read one
+// line at a time, so that it never has to be in memory all at once.
+// Even an input too big to fit takes a few hundred bytes.
+//
+// Elves are numbered from 1, in the order of the input, and blank lines
+// only separate them (so extra blank lines aren't elves carrying nothing).
+// `aoc analyze`, and `aoc run` on a streamed input, report the statistics
+// and the elves carrying the most calories; the statistics are also
+// logged every `PROGRESS_INTERVAL` elves:
+//
+//      aoc analyze 1 --param top=10
+//      aoc gen 1 --size 10000000 | RUST_LOG=trace aoc run 1 --input -
+//
+struct Calories {
+    top: TopK<(usize, u64), u64>,
+    elves: usize,
+    max: u64,
+    total: u128,
+    current: Option<u64>,
+}
+
+const PROGRESS_INTERVAL: usize = 1_000_000;
+
+impl Calories {
+    fn read(mut input: impl BufRead, top: usize) -> anyhow::Result<Self> {
+        let mut calories = Calories {
+            top: TopK::by_key(top, |&(_elf, calories): &(usize, u64)| calories),
+            elves: 0,
+            max: 0,
+            total: 0,
+            current: None,
+        };
+
+        // Each elf's numbers are separated from the next elf's by a blank line
+        let mut text = String::new();
+        let mut number = 0;
+        while input.read_line(&mut text)? > 0 {
+            number += 1;
+            let line = Line::new(number, text.trim_end_matches('\n').trim_end_matches('\r'));
+            if line.text.is_empty() {
+                calories.end_elf();
+            } else {
+                let current = calories.current.get_or_insert(0);
+                *current = current.checked_add(line.parse(line.text)?)
+                    .ok_or_else(|| line.invalid("too many calories for one elf"))?;
+            }
+            text.clear();
+        }
+        calories.end_elf();
+
+        debug!("{}", calories.summary());
+        Ok(calories)
+    }
+
+    fn end_elf(&mut self) {
+        let Some(current) = self.current.take() else { return };
+        self.elves += 1;
+        self.max = self.max.max(current);
+        self.total += current as u128;
+        self.top.push((self.elves, current));
+        if self.elves.is_multiple_of(PROGRESS_INTERVAL) {
+            trace!("{}", self.summary());
+        }
+    }
+
+    // The calories carried by the top elves, together
+    fn top_total(&self) -> u128 {
+        self.top.sorted().iter().map(|&&(_elf, calories)| calories as u128).sum()
+    }
+
+    fn summary(&self) -> String {
+        if self.elves == 0 {
+            return "no elves".to_string();
+        }
+        let mean = self.total as f64 / self.elves as f64;
+        format!("{} elves, max {}, mean {mean:.1} per elf, top {} carry {}", self.elves, self.max, self.top.k(), self.top_total())
+    }
+
+    // The summary, then the top elves, most calories first
+    fn report(&self) -> String {
+        let mut report = self.summary();
+        for (elf, total) in self.top.sorted() {
+            report += &format!("\nelf {elf}: {total} calories");
+        }
+        report
+    }
+}
+
+#[test]
+fn test_calories() {
+    let input = include_str!("../../../fixtures/day01/example.txt");
+    let calories = Calories::read(input.as_bytes(), 3).unwrap();
+    assert_eq!(calories.top.sorted(), vec![&(4, 24000), &(3, 11000), &(5, 10000)]);
+    assert_eq!((calories.elves, calories.max, calories.total), (5, 24000, 55000));
+    assert_eq!(calories.summary(), "5 elves, max 24000, mean 11000.0 per elf, top 3 carry 45000");
+    assert_eq!(calories.report().lines().skip(1).collect::<Vec<_>>(),
+               vec!["elf 4: 24000 calories", "elf 3: 11000 calories", "elf 5: 10000 calories"]);
+}
+
+#[test]
+fn test_blank_lines() {
+    // Extra blank lines, between elves or at the end, aren't elves
+    let calories = Calories::read("\n1000\n\n\n\n2000\n3000\n\n\n".as_bytes(), 3).unwrap();
+    assert_eq!((calories.elves, calories.max, calories.total), (2, 5000, 6000));
+    assert_eq!(calories.top.sorted(), vec![&(2, 5000), &(1, 1000)]);
+
+    let calories = Calories::read("\n\n".as_bytes(), 3).unwrap();
+    assert_eq!((calories.elves, calories.summary().as_str()), (0, "no elves"));
+
+    // An elf carrying nothing is still an elf
+    let calories = Calories::read("1000\n\n0\n".as_bytes(), 3).unwrap();
+    assert_eq!(calories.top.sorted(), vec![&(1, 1000), &(2, 0)]);
+}
+
+#[test]
+fn test_big_totals() {
+    // More than a u32 per elf, and more than a u64 altogether
+    let input = format!("{}\n{}\n\n{}\n", u32::MAX, u32::MAX, u64::MAX);
+    let calories = Calories::read(input.as_bytes(), 2).unwrap();
+    assert_eq!(calories.max, u64::MAX);
+    assert_eq!(calories.top_total(), u64::MAX as u128 + 2 * u32::MAX as u128);
+    let Err(err) = Calories::read(format!("{}\n1\n", u64::MAX).as_bytes(), 2) else { panic!("no overflow") };
+    assert!(err.to_string().contains("too many calories for one elf"), "{err}");
+}
@@ -0,0 +1,382 @@
+use std::str::FromStr;
+use anyhow::{bail, Context};
+use itertools::Itertools;
+use crate::{Param, Params, Rng, Solution};
+use crate::parse::{lines, Line, ParseError};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn part1(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input, &game(params)?)?.to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input, &game(params)?)?.to_string())
+    }
+
+    fn analyze(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(analyze(input, &game(params)?)?)
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param {
+                name: "game",
+                default: "rps",
+                help: "rps (rock paper scissors), rpsls (plus lizard and Spock), or the moves in cycle order, \
+                       as name:opponent's code:my code:score separated by commas (see Game::from_str)",
+            },
+            Param { name: "outcomes", default: "0,3,6", help: "Points for losing, a draw, and winning" },
+        ]
+    }
+
+    // `size` rounds.  The second column has to work both as my move and
+    // as an outcome, so in games with more than three moves, some of my
+    // moves never come up.
+    fn generate(&self, rng: &mut Rng, size: usize, params: &Params) -> anyhow::Result<String> {
+        let game = game(params)?;
+        let opponent = game.moves.iter().map(|m| m.opponent.as_str()).collect::<Vec<_>>();
+        let second = game.moves.iter().map(|m| m.me.as_str())
+            .filter(|me| OUTCOMES.iter().any(|(code, _)| code == me))
+            .collect::<Vec<_>>();
+        if second.is_empty() {
+            bail!("none of my moves' codes is X, Y or Z, so no input works for both parts");
+        }
+        Ok((0..size).map(|_| {
+            format!("{} {}\n", rng.choose(&opponent), rng.choose(&second))
+        }).collect())
+    }
+}
+
+//
+// Part 1
+//
+// The second column is my move.
+//
+fn part1(input: &str, game: &Game) -> Result<i32, ParseError> {
+    let mut result = 0;
+    for line in lines(input) {
+        let [opponent, me] = line.fields(" ")?;
+        let opponent = game.opponent_move(&line, opponent)?;
+        let me = game.my_move(&line, me)?;
+        result += game.score(me, opponent);
+    }
+    Ok(result)
+}
+
+//
+// Part 2
+//
+// The second column is how the round needs to end, so I have to figure
+// out which move to make.
+//
+fn part2(input: &str, game: &Game) -> Result<i32, ParseError> {
+    let mut result = 0;
+    for line in lines(input) {
+        let [opponent, outcome] = line.fields(" ")?;
+        let opponent = game.opponent_move(&line, opponent)?;
+        let outcome = match OUTCOMES.iter().find(|(code, _)| *code == outcome) {
+            Some(&(_, outcome)) => outcome,
+            None => return Err(line.error(outcome, "expected X, Y or Z"))
+        };
+        result += game.score(game.move_for(opponent, outcome), opponent);
+    }
+    Ok(result)
+}
+
+//
+// How good is the strategy guide?  Part 1 reads the second column as my
+// move, but it could have meant other moves.  Score every mapping from
+// the second column to moves, and compare them with the best reply to
+// each of the opponent's moves, and with what the moves would score
+// against an opponent who plays at random.
+//
+fn analyze(input: &str, game: &Game) -> Result<String, ParseError> {
+    // How often each (opponent's move, second column) came up
+    let n = game.moves.len();
+    let mut counts = vec![vec![0; n]; n];
+    for line in lines(input) {
+        let [opponent, me] = line.fields(" ")?;
+        counts[game.opponent_move(&line, opponent)?][game.my_move(&line, me)?] += 1;
+    }
+    let rounds = counts.iter().flatten().sum::<i32>();
+
+    // `mapping[code]` is the move that the second column's code means
+    let total = |mapping: &[usize]| -> i32 {
+        (0..n).cartesian_product(0..n)
+            .map(|(opponent, code)| counts[opponent][code] * game.score(mapping[code], opponent))
+            .sum()
+    };
+    let describe = |mapping: &[usize]| {
+        let mut codes = (0..n).map(|code| (&game.moves[code].me, &game.moves[mapping[code]].name)).collect::<Vec<_>>();
+        codes.sort();
+        codes.iter().map(|(code, name)| format!("{code}={name}")).join(", ")
+    };
+    let part1 = (0..n).collect::<Vec<_>>();
+    let mappings = (0..n).permutations(n).map(|mapping| (total(&mapping), mapping)).collect::<Vec<_>>();
+    let (best, best_mapping) = mappings.iter().max_by_key(|(score, _)| score).unwrap();
+    let (worst, worst_mapping) = mappings.iter().min_by_key(|(score, _)| score).unwrap();
+
+    // Knowing the opponent's move every round
+    let best_reply = (0..n).map(|opponent| {
+        counts[opponent].iter().sum::<i32>() * (0..n).map(|me| game.score(me, opponent)).max().unwrap()
+    }).sum::<i32>();
+
+    // Against every move equally often
+    let average = |me: usize| (0..n).map(|opponent| game.score(me, opponent)).sum::<i32>() as f64 / n as f64;
+    let expected = (0..n).cartesian_product(0..n)
+        .map(|(opponent, code)| counts[opponent][code] as f64 * average(code))
+        .sum::<f64>();
+    let always = (0..n).max_by(|&a, &b| average(a).total_cmp(&average(b))).unwrap();
+
+    let per_round = |score: f64| score / rounds.max(1) as f64;
+    Ok([
+        format!("{rounds} rounds"),
+        format!("Part 1 ({}): {}", describe(&part1), total(&part1)),
+        format!("Best mapping ({}): {best}", describe(best_mapping)),
+        format!("Worst mapping ({}): {worst}", describe(worst_mapping)),
+        format!("Best reply to every move: {best_reply}"),
+        "Against a random opponent:".to_string(),
+        format!("  part 1's moves: {expected:.1} expected ({:.2} per round)", per_round(expected)),
+        format!("  always {}: {:.1} expected ({:.2} per round)",
+            game.moves[always].name, rounds as f64 * average(always), average(always)),
+    ].join("\n") + "\n")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+// How the second column says the round should end (part 2)
+const OUTCOMES: [(&str, Outcome); 3] = [("X", Outcome::Lose), ("Y", Outcome::Draw), ("Z", Outcome::Win)];
+
+struct Move {
+    name: String,
+    // The codes for this move in the first column (the opponent's)
+    // and the second (mine, in part 1)
+    opponent: String,
+    me: String,
+    score: i32,
+}
+
+//
+// The rules of a game like rock paper scissors: the moves, which ones
+// beat which, and the scores.  A round scores the points for my move,
+// plus the points for how it came out.
+//
+struct Game {
+    moves: Vec<Move>,
+    // beats[a][b] is whether move a beats move b
+    beats: Vec<Vec<bool>>,
+    // The points for losing, a draw, and winning
+    outcome_scores: [i32; 3],
+}
+
+impl Game {
+    //
+    // A game where the moves go around a circle, and each move beats
+    // the half of the others just before it.  So there has to be an odd
+    // number of moves, and at least three, for every outcome to be
+    // possible against every move (part 2).
+    //
+    fn cyclic(moves: Vec<Move>) -> anyhow::Result<Self> {
+        let n = moves.len();
+        if n < 3 || n.is_multiple_of(2) {
+            bail!("a cyclic game needs an odd number of moves, at least 3; got {n}");
+        }
+        for (a, b) in moves.iter().tuple_combinations() {
+            for (what, a, b) in [("name", &a.name, &b.name), ("opponent's code", &a.opponent, &b.opponent), ("code", &a.me, &b.me)] {
+                if a == b {
+                    bail!("two moves have the {what} {a:?}");
+                }
+            }
+        }
+        let beats = (0..n).map(|a| {
+            (0..n).map(|b| (1..=n / 2).contains(&((a + n - b) % n))).collect()
+        }).collect();
+        Ok(Game { moves, beats, outcome_scores: [0, 3, 6] })
+    }
+
+    #[cfg(test)]
+    fn rock_paper_scissors() -> Self {
+        ROCK_PAPER_SCISSORS.parse().unwrap()
+    }
+
+    #[cfg(test)]
+    fn rock_paper_scissors_lizard_spock() -> Self {
+        ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse().unwrap()
+    }
+
+    fn outcome(&self, me: usize, opponent: usize) -> Outcome {
+        if self.beats[me][opponent] {
+            Outcome::Win
+        } else if self.beats[opponent][me] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    fn score(&self, me: usize, opponent: usize) -> i32 {
+        self.moves[me].score + self.outcome_scores[self.outcome(me, opponent) as usize]
+    }
+
+    // The move that ends the round this way.  If more than one does, the
+    // one worth the most points.
+    fn move_for(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+            .filter(|&me| self.outcome(me, opponent) == outcome)
+            .max_by_key(|&me| self.moves[me].score)
+            .expect("every outcome is possible against every move")
+    }
+
+    fn opponent_move(&self, line: &Line, code: &str) -> Result<usize, ParseError> {
+        self.moves.iter().position(|m| m.opponent == code)
+            .ok_or_else(|| line.error(code, expected(self.moves.iter().map(|m| m.opponent.as_str()))))
+    }
+
+    fn my_move(&self, line: &Line, code: &str) -> Result<usize, ParseError> {
+        self.moves.iter().position(|m| m.me == code)
+            .ok_or_else(|| line.error(code, expected(self.moves.iter().map(|m| m.me.as_str()))))
+    }
+}
+
+const ROCK_PAPER_SCISSORS: &str = "rock:A:X:1,paper:B:Y:2,scissors:C:Z:3";
+
+// Spock smashes scissors and vaporizes rock, and so on
+const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "rock:A:V:1,Spock:E:Z:5,paper:B:W:2,lizard:D:Y:4,scissors:C:X:3";
+
+//
+// A game by name (rps or rpsls), or its moves in cycle order, each
+// beating the half of the others before it, with the codes for the
+// move in each column and its points:
+//
+//      rock:A:X:1,paper:B:Y:2,scissors:C:Z:3
+//
+impl FromStr for Game {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules = match s {
+            "rps" => ROCK_PAPER_SCISSORS,
+            "rpsls" => ROCK_PAPER_SCISSORS_LIZARD_SPOCK,
+            _ if !s.contains(':') => bail!("unknown game {s:?}; expected rps, rpsls, or moves like {ROCK_PAPER_SCISSORS}"),
+            _ => s,
+        };
+        let moves = rules.split(',').map(|entry| {
+            let Some((name, opponent, me, score)) = entry.split(':').collect_tuple() else {
+                bail!("expected name:opponent's code:my code:score; got {entry:?}");
+            };
+            for code in [name, opponent, me] {
+                if code.is_empty() || code.contains(char::is_whitespace) {
+                    bail!("names and codes can't be empty or have spaces; got {entry:?}");
+                }
+            }
+            let score = score.parse().with_context(|| format!("bad score in {entry:?}"))?;
+            Ok(Move { name: name.to_string(), opponent: opponent.to_string(), me: me.to_string(), score })
+        }).collect::<anyhow::Result<Vec<_>>>()?;
+        Game::cyclic(moves)
+    }
+}
+
+// The game, with its outcome scores
+fn game(params: &Params) -> anyhow::Result<Game> {
+    let mut game = params.get::<Game>("game")?;
+    let outcomes = params.get::<String>("outcomes")?;
+    let scores = outcomes.split(',').map(str::parse).collect::<Result<Vec<i32>, _>>();
+    game.outcome_scores = match scores.ok().and_then(|scores| scores.try_into().ok()) {
+        Some(scores) => scores,
+        None => bail!("expected points for losing, a draw, and winning, like 0,3,6; got {outcomes:?}"),
+    };
+    Ok(game)
+}
+
+// ["A", "B", "C"] => "expected A, B or C"
+fn expected<'a>(codes: impl Iterator<Item = &'a str>) -> String {
+    let mut codes = codes.collect::<Vec<_>>();
+    codes.sort();
+    let last = codes.pop().unwrap();
+    format!("expected {} or {last}", codes.join(", "))
+}
+
+#[test]
+fn test_rules() {
+    let game = Game::rock_paper_scissors();
+    let [rock, paper, scissors] = [0, 1, 2];
+    assert_eq!(game.outcome(paper, rock), Outcome::Win);
+    assert_eq!(game.outcome(scissors, paper), Outcome::Win);
+    assert_eq!(game.outcome(rock, scissors), Outcome::Win);
+    assert_eq!(game.outcome(rock, paper), Outcome::Lose);
+    assert_eq!(game.outcome(rock, rock), Outcome::Draw);
+
+    // Every move beats two others, and loses to the other two
+    let game = Game::rock_paper_scissors_lizard_spock();
+    let code = |code: &str| game.moves.iter().position(|m| m.opponent == code).unwrap();
+    let [rock, scissors, lizard, spock] = ["A", "C", "D", "E"].map(code);
+    assert_eq!(game.outcome(spock, scissors), Outcome::Win);
+    assert_eq!(game.outcome(lizard, spock), Outcome::Win);
+    assert_eq!(game.outcome(rock, lizard), Outcome::Win);
+    for a in 0..5 {
+        assert_eq!((0..5).filter(|&b| game.beats[a][b]).count(), 2);
+        assert_eq!((0..5).filter(|&b| game.beats[b][a]).count(), 2);
+    }
+}
+
+#[test]
+fn test_rule_descriptions() {
+    let example = include_str!("../../../fixtures/day02/example.txt");
+
+    // Rock paper scissors, spelled out, with the codes moved around
+    let spelled = "stone:A:Y:1,sheet:B:Z:2,blades:C:X:3".parse::<Game>().unwrap();
+    assert_eq!(spelled.moves.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(), vec!["stone", "sheet", "blades"]);
+    let [stone, sheet, blades] = [0, 1, 2];
+    assert_eq!(spelled.outcome(sheet, stone), Outcome::Win);
+    assert_eq!(spelled.outcome(stone, blades), Outcome::Win);
+    // A Y is now rock (a draw), B X is scissors (a win), C Z is paper (a loss)
+    assert_eq!(part1(example, &spelled).unwrap(), 4 + 9 + 2);
+    assert_eq!(part2(example, &spelled).unwrap(), 12);
+
+    // Other outcome scores
+    let params = Params::new(Day02.params(), [("game", "stone:A:Y:1,sheet:B:Z:2,blades:C:X:3"), ("outcomes", "1,2,10")]).unwrap();
+    assert_eq!(part1(example, &game(&params).unwrap()).unwrap(), (1 + 2) + (3 + 10) + (2 + 1));
+
+    for (rules, error) in [
+        ("rock:A:X:1,paper:B:Y:2", "an odd number of moves, at least 3; got 2"),
+        ("rock:A:X:1", "an odd number of moves, at least 3; got 1"),
+        ("a:A:X:1,b:B:Y:2,c:C:Z:3,d:D:V:4", "an odd number of moves, at least 3; got 4"),
+        ("rock:A:X:1,paper:B:X:2,scissors:C:Z:3", "two moves have the code \"X\""),
+        ("rock:A:X:1,rock:B:Y:2,scissors:C:Z:3", "two moves have the name \"rock\""),
+        ("rock:A:X:1,paper:B:Y,scissors:C:Z:3", "expected name:opponent's code:my code:score; got \"paper:B:Y\""),
+        ("rock:A:X:one,paper:B:Y:2,scissors:C:Z:3", "bad score in \"rock:A:X:one\""),
+        ("rock:A:X:1,paper:B: :2,scissors:C:Z:3", "can't be empty or have spaces"),
+        ("chess", "unknown game \"chess\""),
+    ] {
+        let err = rules.parse::<Game>().err().unwrap();
+        assert!(format!("{err:#}").contains(error), "{rules}: {err:#}");
+    }
+    let params = Params::new(Day02.params(), [("outcomes", "0,3")]).unwrap();
+    assert!(game(&params).is_err());
+}
+
+#[test]
+fn test_analyze() {
+    let input = include_str!("../../../fixtures/day02/example.txt");
+    assert_eq!(analyze(input, &Game::rock_paper_scissors()).unwrap(), "\
+3 rounds
+Part 1 (X=rock, Y=paper, Z=scissors): 15
+Best mapping (X=scissors, Y=paper, Z=rock): 24
+Worst mapping (X=rock, Y=scissors, Z=paper): 6
+Best reply to every move: 24
+Against a random opponent:
+  part 1's moves: 15.0 expected (5.00 per round)
+  always scissors: 18.0 expected (6.00 per round)
+");
+}
@@ -0,0 +1,306 @@
+use anyhow::bail;
+use itertools::Itertools;
+use crate::{Param, Params, Rng, Solution};
+use crate::parse::{lines, truncated, Line, ParseError};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input, group(params)?)?.to_string())
+    }
+
+    // Every group's badge, or what could be its badge
+    fn analyze(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(analyze(input, group(params)?)?)
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "group", default: "3", help: "Number of elves in a group, who share a badge (part 2)" },
+        ]
+    }
+
+    // `size` groups of elves
+    fn generate(&self, rng: &mut Rng, size: usize, params: &Params) -> anyhow::Result<String> {
+        let group = params.get("group")?;
+        if !(2..=17).contains(&group) {
+            bail!("can only generate groups of 2 to 17 elves");
+        }
+        Ok(generate(rng, size, group))
+    }
+}
+
+//
+// Part 1
+//
+// For each line of input, we are asked to find which one character
+// appears in both halves of the line.  We then convert that character
+// to a number ("priority").  The final answer is the sum of those
+// numbers ("priorities").
+//
+fn part1(input: &str) -> Result<u32, ParseError> {
+    lines(input).map(|line| {
+        let items = items(&line)?;
+        if items.len() % 2 != 0 {
+            return Err(line.invalid("expected an even number of items"));
+        }
+        let (first, second) = items.split_at(items.len()/2);
+        let first_half = first.iter().copied().collect::<ItemSet>();
+        let second_half = second.iter().copied().collect::<ItemSet>();
+        let overlap = first_half.intersection(second_half);
+        match overlap.len() {
+            0 => Err(line.invalid("no item is in both compartments")),
+            1 => Ok(overlap.priority_sum()),
+            _ => Err(line.invalid(format!("several items are in both compartments: {overlap}"))),
+        }
+    }).sum()
+}
+
+//
+// Part 2
+//
+// This time, we're trying to find the one common letter for every group
+// of lines (3, unless the "group" param says otherwise).  Use the same
+// mechanism to compute "priorities" and sum them.
+//
+fn part2(input: &str, group: usize) -> Result<u32, ParseError> {
+    groups(input, group)?.into_iter().map(|Group { last, candidates, .. }| {
+        match candidates.len() {
+            0 => Err(last.invalid("no item is common to the group")),
+            1 => Ok(candidates.priority_sum()),
+            _ => Err(last.invalid(format!("several items are common to the group: {candidates}"))),
+        }
+    }).sum()
+}
+
+fn group(params: &Params) -> anyhow::Result<usize> {
+    let group = params.get("group")?;
+    if group == 0 {
+        bail!("groups need at least one elf");
+    }
+    Ok(group)
+}
+
+//
+// A group of elves: its last line, every item that could be its badge
+// (the ones they all carry), and every item any of them carries.
+//
+struct Group<'a> {
+    last: Line<'a>,
+    candidates: ItemSet,
+    carried: ItemSet,
+}
+
+fn groups(input: &str, group: usize) -> Result<Vec<Group<'_>>, ParseError> {
+    if !input.lines().count().is_multiple_of(group) {
+        return Err(truncated(input, format!("groups of {group} lines")));
+    }
+    let lines = lines(input).collect::<Vec<_>>();
+    lines.chunks(group).map(|rucksacks| {
+        let mut candidates = ItemSet::ALL;
+        let mut carried = ItemSet::default();
+        for line in rucksacks {
+            let items = items(line)?.into_iter().collect();
+            candidates = candidates.intersection(items);
+            carried = carried.union(items);
+        }
+        Ok(Group { last: rucksacks[group-1], candidates, carried })
+    }).collect()
+}
+
+//
+// Each group's badge, or all of the items that could be its badge when
+// there isn't exactly one (which part 2 only reports for the first such
+// group), out of how many different items the group carries.
+//
+fn analyze(input: &str, group: usize) -> Result<String, ParseError> {
+    let groups = groups(input, group)?;
+    let count = |n: u32| groups.iter().filter(|g| g.candidates.len() == n).count();
+    let mut report = format!("{} groups of {group}: {} with one badge, {} with none, {} with several\n",
+        groups.len(), count(1), count(0), groups.len() - count(1) - count(0));
+    for Group { last, candidates, carried } in &groups {
+        let (first, last) = (last.number + 1 - group, last.number);
+        let badge = match candidates.len() {
+            0 => "no badge".to_string(),
+            1 => format!("badge {candidates}"),
+            n => format!("{n} possible badges: {candidates}"),
+        };
+        report += &format!("lines {first}-{last}: {badge} (of {} different items)\n", carried.len());
+    }
+    Ok(report)
+}
+
+// The items in a rucksack, which must all be letters
+fn items(line: &Line) -> Result<Vec<char>, ParseError> {
+    line.text.char_indices().map(|(i, ch)| {
+        if ch.is_ascii_alphabetic() {
+            Ok(ch)
+        } else {
+            Err(line.error(&line.text[i..i+ch.len_utf8()], "expected a letter"))
+        }
+    }).collect()
+}
+
+fn priority(ch: char) -> u32 {
+    match ch {
+        'a'..='z' => 1 + ch as u32 - 'a' as u32,
+        'A'..='Z' => 27 + ch as u32 - 'A' as u32,
+        _ => unreachable!("Not a letter")
+    }
+}
+
+// The item with the given priority (from 1 to 52)
+fn item(priority: u32) -> char {
+    match priority {
+        1..=26 => (b'a' + (priority - 1) as u8) as char,
+        27..=52 => (b'A' + (priority - 27) as u8) as char,
+        _ => unreachable!("Not a priority")
+    }
+}
+
+//
+// A set of items, as one bit per item: bit 0 is priority 1 ('a'), up to
+// bit 51 for priority 52 ('Z').  Items are added by collecting letters,
+// and come back out in order of priority.
+//
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct ItemSet(u64);
+
+impl ItemSet {
+    const ALL: ItemSet = ItemSet((1 << 52) - 1);
+
+    fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    fn iter(self) -> impl Iterator<Item = char> {
+        let mut bits = self.0;
+        std::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let priority = bits.trailing_zeros() + 1;
+            bits &= bits - 1;
+            Some(item(priority))
+        })
+    }
+
+    fn priority_sum(self) -> u32 {
+        self.iter().map(priority).sum()
+    }
+
+    fn bit(item: char) -> u64 {
+        1 << (priority(item) - 1)
+    }
+}
+
+impl FromIterator<char> for ItemSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        ItemSet(iter.into_iter().fold(0, |bits, item| bits | Self::bit(item)))
+    }
+}
+
+// The items, like "a, b, C"
+impl std::fmt::Display for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.iter().join(", "))
+    }
+}
+
+//
+// Each group has a badge, and the other 51 letters are shared out among
+// the elves (17 each for a group of 3): one compartment holds half of
+// an elf's letters, the other compartment the other half, and the item
+// in both compartments is the last letter or the badge.  So the group
+// has only the badge in common, and each rucksack has only one item in
+// both compartments.
+//
+fn generate(rng: &mut Rng, size: usize, group: usize) -> String {
+    let own_letters = 51 / group;
+    let half = (own_letters - 1) / 2;
+    let mut result = String::new();
+    for _ in 0..size {
+        let mut letters = ('a'..='z').chain('A'..='Z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        let badge = letters[0];
+        for own in letters[1..].chunks(own_letters).take(group) {
+            let shared = if rng.chance(0.25) { badge } else { own[2*half] };
+            let length = rng.below(14) + 3;
+            let mut compartments = [&own[0..half], &own[half..2*half]].map(|choices| {
+                let mut items = (0..length).map(|_| *rng.choose(choices)).collect::<Vec<_>>();
+                items[0] = shared;
+                items
+            });
+            if shared != badge {
+                let side = rng.below(2);
+                compartments[side][1] = badge;
+            }
+            for mut items in compartments {
+                rng.shuffle(&mut items);
+                result.extend(items);
+            }
+            result.push('\n');
+        }
+    }
+    result
+}
+
+#[test]
+fn test_item_set() {
+    let first = "vJrwpWtwJgWr".chars().collect::<ItemSet>();
+    let second = "hcsFMMfFFhFp".chars().collect::<ItemSet>();
+    assert_eq!(first.intersection(second).iter().collect::<Vec<_>>(), vec!['p']);
+    assert_eq!(first.to_string(), "g, p, r, t, v, w, J, W");
+    assert_eq!(first.union(second).len(), 14);
+    assert_eq!(ItemSet::ALL.len(), 52);
+    assert_eq!(ItemSet::ALL.priority_sum(), (1..=52).sum());
+    assert_eq!("aZ".chars().collect::<ItemSet>().priority_sum(), 53);
+}
+
+#[test]
+fn test_badges() {
+    let input = include_str!("../../../fixtures/day03/example.txt");
+    let groups = groups(input, 2).unwrap();
+    let candidates = groups.iter().map(|g| (g.last.number, g.candidates.to_string())).collect::<Vec<_>>();
+    assert_eq!(candidates, vec![(2, "f, r, s, F, M".to_string()), (4, "q, v, w, B, T".to_string()), (6, "G, J, Z".to_string())]);
+    assert_eq!(groups.iter().map(|g| g.carried.len()).collect::<Vec<_>>(), vec![25, 25, 20]);
+
+    let error = part2(input, 2).unwrap_err();
+    assert_eq!(error.line, 2);
+    assert!(error.to_string().contains("several items are common to the group: f, r, s, F, M"));
+    assert!(part2(input, 4).is_err());
+}
+
+#[test]
+fn test_analyze() {
+    let input = include_str!("../../../fixtures/day03/example.txt");
+    assert_eq!(analyze(input, 3).unwrap(), "\
+2 groups of 3: 2 with one badge, 0 with none, 0 with several
+lines 1-3: badge r (of 31 different items)
+lines 4-6: badge Z (of 30 different items)
+");
+    assert_eq!(analyze(input, 2).unwrap(), "\
+3 groups of 2: 0 with one badge, 0 with none, 3 with several
+lines 1-2: 5 possible badges: f, r, s, F, M (of 25 different items)
+lines 3-4: 5 possible badges: q, v, w, B, T (of 25 different items)
+lines 5-6: 3 possible badges: G, J, Z (of 20 different items)
+");
+    assert_eq!(analyze(input, 6).unwrap().lines().nth(1), Some("lines 1-6: no badge (of 35 different items)"));
+}
@@ -0,0 +1,71 @@
+use std::ops::RangeInclusive;
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, Line, ParseError};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(&parse_input(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(&parse_input(input)?).to_string())
+    }
+
+    // `size` pairs of elves
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            format!("{start}-{}", rng.range(start..=99))
+        };
+        Ok((0..size).map(|_| format!("{},{}\n", range(), range())).collect())
+    }
+}
+
+type RangePair = (RangeInclusive<u32>, RangeInclusive<u32>);
+
+//
+// Parsing the input
+//
+fn parse_input(input: &str) -> Result<Vec<RangePair>, ParseError> {
+    lines(input).map(|line| {
+        let (first, second) = line.split_once(",")?;
+        Ok((parse_range(&line, first)?, parse_range(&line, second)?))
+    }).collect()
+}
+
+// A range like "2-4"
+fn parse_range(line: &Line, piece: &str) -> Result<RangeInclusive<u32>, ParseError> {
+    let (start, end) = line.split_piece(piece, "-")?;
+    Ok(line.parse(start)?..=line.parse(end)?)
+}
+
+//
+// Part 1
+//
+// One range fully contains another range if it contains both the start and end
+// of the other range.
+//
+fn part1(range_pairs: &[RangePair]) -> usize {
+    range_pairs.iter().filter(|(r1, r2)|
+        (r1.contains(r2.start()) && r1.contains(r2.end())) ||
+        (r2.contains(r1.start()) && r2.contains(r1.end()))
+    ).count()
+}
+
+//
+// Part 2
+//
+// One range overlaps another if it contains the start *or* end of the other.
+//
+fn part2(range_pairs: &[RangePair]) -> usize {
+    range_pairs.iter().filter(|(r1, r2)|
+        (r1.contains(r2.start()) || r1.contains(r2.end())) ||
+        (r2.contains(r1.start()) || r2.contains(r1.end()))
+    ).count()
+}
@@ -0,0 +1,182 @@
+use std::str::FromStr;
+use anyhow::anyhow;
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, truncated, Line, ParseError};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        part2(input)
+    }
+
+    // Nine stacks, and `size` moves
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+fn part1(input: &str) -> anyhow::Result<String> {
+    // Parse the input
+    let (mut stacks, movements) = parse_input(input)?;
+
+    // Now execute the movements
+    for Movement{count, source, dest} in movements {
+        for _ in 0..count {
+            let c = stacks[source-1].pop()
+                .ok_or_else(|| anyhow!("stack {source} is empty"))?;
+            stacks[dest-1].push(c);
+
+            // Note: This doesn't compile:
+            // assert_ne!(source, dest);
+            // stacks[dest].push(stacks[source].pop().unwrap())
+            // because it requires borrowing stacks[] as mutuable twice
+        }
+    }
+
+    top_letters(&stacks)
+}
+
+fn part2(input: &str) -> anyhow::Result<String> {
+    // Parse the input
+    let (mut stacks, movements) = parse_input(input)?;
+
+    // Now execute the movements
+    for Movement{count, source: index, dest} in movements {
+        let source = &mut stacks[index - 1];
+        if count > source.len() {
+            return Err(anyhow!("stack {index} has fewer than {count} crates"));
+        }
+        let moved = source.split_off(source.len() - count);
+        let dest = &mut stacks[dest - 1];
+        dest.extend_from_slice(&moved);
+    }
+
+    top_letters(&stacks)
+}
+
+// Finally, grab the top letter on each stack
+fn top_letters(stacks: &[Vec<char>]) -> anyhow::Result<String> {
+    stacks.iter().enumerate().map(|(i, stack)| {
+        stack.last().copied().ok_or_else(|| anyhow!("stack {} is empty", i+1))
+    }).collect()
+}
+
+struct Movement {
+    count: usize,
+    source: usize,
+    dest: usize
+}
+
+impl FromStr for Movement {
+    type Err = ParseError;
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // Looks like "move 1 from 2 to 1"
+        let line = Line::new(1, text);
+        let [verb, count, from, source, to, dest] = line.fields(" ")?;
+        for (word, expected) in [(verb, "move"), (from, "from"), (to, "to")] {
+            if word != expected {
+                return Err(line.error(word, format!("expected {expected:?}")));
+            }
+        }
+        Ok(Movement {
+            count: line.parse(count)?,
+            source: line.parse(source)?,
+            dest: line.parse(dest)?
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Vec<char>>, Vec<Movement>), ParseError> {
+    // Start by constructing empty stacks.  We examine the length of the
+    // first line of input to figure out how many stacks there are.
+    // That assumes that those lines are padded with spaces if needed.
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    let mut lines = lines(input);
+    let mut line = lines.next().ok_or_else(|| truncated(input, "stacks of crates"))?;
+    let num_stacks = (line.text.len() + 1) / 4;
+    for _ in 0..num_stacks {
+        stacks.push(Vec::new());
+    }
+
+    // Now fill in the stacks
+    while line.text.contains('[') {
+        let letters = line.text.chars().skip(1).step_by(4);
+        for (stack, c) in stacks.iter_mut().zip(letters).filter(|(_,c)| *c != ' ') {
+            stack.push(c);
+        }
+        line = lines.next().ok_or_else(|| truncated(input, "stack numbers"))?;
+    }
+
+    // We want the items in the first line to be on the top of the stack,
+    // which means we need to reverse each of the stacks.
+    for stack in stacks.iter_mut() {
+        stack.reverse();
+    }
+
+    // Consume the blank line
+    match lines.next() {
+        Some(line) if line.text.is_empty() => {},
+        Some(line) => return Err(line.invalid("expected a blank line")),
+        None => return Err(truncated(input, "a blank line"))
+    }
+
+    // Now parse the lines with the movement instructions
+    let movements = lines.map(|line| {
+        let movement: Movement = line.parse_nested(line.text)?;
+        for index in [movement.source, movement.dest] {
+            if index == 0 || index > num_stacks {
+                return Err(line.invalid(format!("no stack number {index}")));
+            }
+        }
+        Ok(movement)
+    }).collect::<Result<_, _>>()?;
+
+    Ok((stacks, movements))
+}
+
+//
+// A move never takes the last crate from a stack, so none of them are
+// empty at the end (and both parts have an answer).
+//
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut stacks = (0..9).map(|_| {
+        (0..rng.range(1..=8)).map(|_| rng.below(26) as u8 + b'A').collect::<Vec<_>>()
+    }).collect::<Vec<_>>();
+    stacks[0].push(b'A');      // At least one stack has a crate to move
+
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut result = String::new();
+    for level in (0..height).rev() {
+        let row = stacks.iter().map(|stack| match stack.get(level) {
+            Some(&c) => format!("[{}]", c as char),
+            None => "   ".to_string(),
+        }).collect::<Vec<_>>();
+        result += &row.join(" ");
+        result.push('\n');
+    }
+    result += " 1   2   3   4   5   6   7   8   9 \n\n";
+
+    for _ in 0..size {
+        let movable = (0..9).filter(|&i| stacks[i].len() > 1).collect::<Vec<_>>();
+        let source = *rng.choose(&movable);
+        let mut dest = rng.below(8);
+        if dest >= source {
+            dest += 1;
+        }
+        let count = rng.below(stacks[source].len() - 1) + 1;
+        let keep = stacks[source].len() - count;
+        let moved = stacks[source].split_off(keep);
+        stacks[dest].extend(moved);
+        result += &format!("move {count} from {} to {}\n", source + 1, dest + 1);
+    }
+    result
+}
@@ -1,24 +1,38 @@
+use anyhow::Context;
 use multiset::HashMultiSet;
+use crate::{Params, Rng, Solution};
 
-fn main() {
-    let path = std::env::args().nth(1)
-        .unwrap_or_else(|| "src/bin/day06/input.txt".into());
-    let input = std::fs::read_to_string(path).unwrap();
-    let chars = input.chars().collect::<Vec<char>>();
+pub struct Day06;
 
-    //
-    // Part 1
-    //
-    let result1 = find_distinct(&chars, 4).unwrap();
-    println!("Part 1: {}", result1);
-    assert_eq!(result1, 1804);
+impl Solution for Day06 {
+    fn day(&self) -> u8 {
+        6
+    }
 
-    //
-    // Part 2
-    //
-    let result2 = find_distinct(&chars, 14).unwrap();
-    println!("Part 2: {}", result2);
-    assert_eq!(result2, 2508);
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        let chars = input.chars().collect::<Vec<char>>();
+        let result = find_distinct(&chars, 4).context("no start-of-packet marker")?;
+        Ok(result.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        let chars = input.chars().collect::<Vec<char>>();
+        let result = find_distinct(&chars, 14).context("no start-of-message marker")?;
+        Ok(result.to_string())
+    }
+
+    // A datastream `size` characters long (at least 14)
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        // Mostly three letters, so the only markers are after a run of
+        // 14 different letters somewhere in the stream
+        let size = size.max(14);
+        let mut chars = (0..size).map(|_| *rng.choose(&['a', 'b', 'c'])).collect::<Vec<_>>();
+        let mut letters = ('a'..='z').collect::<Vec<_>>();
+        rng.shuffle(&mut letters);
+        let start = rng.below(size - 13);
+        chars[start..start + 14].copy_from_slice(&letters[..14]);
+        Ok(chars.into_iter().chain(['\n']).collect())
+    }
 }
 
 //
@@ -50,6 +64,9 @@ fn main() {
 // like VecDeque to maintain the last 4 characters.
 //
 fn find_distinct(chars: &[char], count: usize) -> Option<usize> {
+    if chars.len() < count {
+        return None;
+    }
     let mut set = HashMultiSet::from_iter(chars[0..count].iter().copied());
     for i in count..chars.len() {
         if set.distinct_elements().count() == count {
@@ -0,0 +1,181 @@
+use anyhow::bail;
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(&totals(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(&totals(input)?)?.to_string())
+    }
+
+    // A terminal session listing `size` files
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+//
+// Part 1
+//
+fn part1(totals: &[u64]) -> u64 {
+    totals.iter().filter(|v| **v <= 100_000).sum()
+}
+
+//
+// Part 2
+//
+fn part2(totals: &[u64]) -> anyhow::Result<u64> {
+    let Some(&used) = totals.last() else {
+        bail!("there are no directories");
+    };
+    if used > 70000000 {
+        bail!("the files take up {used}, more than the whole disk (70000000)");
+    }
+    let free_space = 70000000 - used;
+    if free_space >= 30000000 {
+        bail!("there is already {free_space} free, and the update only needs 30000000");
+    }
+    let space_needed = 30000000 - free_space;
+    // "/" is always big enough
+    Ok(totals.iter()
+        .copied()
+        .filter(|v| *v >= space_needed)
+        .min().unwrap_or(used))
+}
+
+//
+// Return the total sizes of all directories.  The size for "/" is last.
+//
+fn totals(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut totals: Vec<u64> = Vec::new();
+    let mut stack:Vec<u64> = Vec::new();
+    for line in lines(input) {
+        if let Some(cd_arg) = line.text.strip_prefix("$ cd ") {
+            if cd_arg == ".." {
+                // Exit current directory.  Save its total size.
+                let total = stack.pop()
+                    .ok_or_else(|| line.error(cd_arg, "already at the top directory"))?;
+                totals.push(total);
+                // Add that total size to its parent (if any)
+                if let Some(top) = stack.last_mut() {
+                    *top += total;
+                }
+            } else {
+                // Entering a new directory; don't care about the name.
+                // Initialize the size (so far) to 0.
+                stack.push(0);
+            }
+        } else if line.text == "$ ls" || line.text.starts_with("dir ") {
+            // Nothing to do.  We'll see the directory's size when we `cd` into it.
+        } else {
+            // Otherwise, it must be a file size and name
+            let (size, _name) = line.split_once(" ")?;
+            let size = line.parse::<u64>(size)?;
+            // Add the size of this file to the current directory
+            *stack.last_mut().ok_or_else(|| line.invalid("file listed outside of a directory"))? += size;
+        }
+    }
+
+    if stack.is_empty() && totals.is_empty() {
+        return Err(truncated(input, "\"$ cd /\""));
+    }
+
+    // Pop any directories still on the stack
+    while let Some(v) = stack.pop() {
+        // Add that total size to its parent (if any)
+        if let Some(top) = stack.last_mut() {
+            *top += v;
+        }
+
+        totals.push(v);
+    }
+
+    Ok(totals)
+}
+
+//
+// The files are put in about `size / 4` directories, and their sizes
+// add up to between 40,000,000 and 70,000,000, so that part 2 has to
+// delete something, and can.
+//
+fn generate(rng: &mut Rng, size: usize) -> String {
+    struct Directory {
+        name: String,
+        children: Vec<usize>,
+        files: Vec<(u32, String)>,
+    }
+
+    let unique_name = |rng: &mut Rng, taken: &mut Vec<String>, extension: bool| loop {
+        let mut name = (0..rng.range(1..=8)).map(|_| rng.lowercase()).collect::<String>();
+        if extension && rng.chance(0.5) {
+            name.push('.');
+            name.extend((0..3).map(|_| rng.lowercase()));
+        }
+        if !taken.contains(&name) {
+            taken.push(name.clone());
+            break name;
+        }
+    };
+
+    // Each directory (except "/") is inside one of the directories before it
+    let mut directories = vec![Directory { name: "/".to_string(), children: vec![], files: vec![] }];
+    let mut names = vec![Vec::new()];
+    for i in 1..(size / 4).max(1) {
+        let parent = rng.below(i);
+        let name = unique_name(rng, &mut names[parent], false);
+        directories.push(Directory { name, children: vec![], files: vec![] });
+        directories[parent].children.push(i);
+        names.push(Vec::new());
+    }
+
+    let total = rng.range(41_000_000..=69_000_000) as u64;
+    // Mostly small files, and a few big ones
+    let weights = (0..size).map(|_| rng.range(1..=100).pow(3) as u64).collect::<Vec<_>>();
+    let sum = weights.iter().sum::<u64>();
+    for weight in weights {
+        let directory = rng.below(directories.len());
+        let name = unique_name(rng, &mut names[directory], true);
+        directories[directory].files.push(((total * weight / sum).max(1) as u32, name));
+    }
+
+    fn list(directories: &[Directory], index: usize, rng: &mut Rng, result: &mut String) {
+        let directory = &directories[index];
+        let mut entries = directory.children.iter()
+            .map(|&child| format!("dir {}", directories[child].name))
+            .chain(directory.files.iter().map(|(size, name)| format!("{size} {name}")))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut entries);
+        result.push_str("$ ls\n");
+        for entry in entries {
+            result.push_str(&entry);
+            result.push('\n');
+        }
+        for &child in &directory.children {
+            result.push_str(&format!("$ cd {}\n", directories[child].name));
+            list(directories, child, rng, result);
+            result.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut result = "$ cd /\n".to_string();
+    list(&directories, 0, rng, &mut result);
+    result
+}
+
+#[test]
+fn test_part2_space() {
+    assert_eq!(part2(&[100, 60_000_000]).unwrap(), 60_000_000);
+    assert_eq!(part2(&[100, 35_000_000]).unwrap_err().to_string(),
+        "there is already 35000000 free, and the update only needs 30000000");
+    assert_eq!(part2(&[80_000_000]).unwrap_err().to_string(),
+        "the files take up 80000000, more than the whole disk (70000000)");
+}
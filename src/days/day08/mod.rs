@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+use anyhow::anyhow;
+use crate::{Params, Rng, Solution};
+use crate::grid::{Grid, Point};
+use crate::parse::ParseError;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(&parse_input(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(&parse_input(input)?)?.to_string())
+    }
+
+    // A forest `size` trees square
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let size = size.max(1);
+        Ok((0..size).map(|_| {
+            (0..size).map(|_| (b'0' + rng.below(10) as u8) as char).chain(['\n']).collect::<String>()
+        }).collect())
+    }
+}
+
+//
+// Part 1
+//
+// I'm going to pretend like I'm on the outside of each row/column,
+// looking in.  Every time I encounter a taller tree, I note that
+// it is visible.
+//
+// It's possible that a given tree is visible from multiple
+// directions.  This means I can't just count how many trees
+// are visible along a given direction.  I think I'm going to
+// construct a HashSet of visible coordinates in order to remove
+// duplicates.
+//
+fn part1(grid: &Grid<i8>) -> usize {
+    // Where to start looking from (just outside the grid), and which
+    // direction to look
+    let mut edges = Vec::new();
+    for row in 0..grid.height() {
+        edges.push((Point::new(-1, row), Point::RIGHT));
+        edges.push((Point::new(grid.width(), row), Point::LEFT));
+    }
+    for col in 0..grid.width() {
+        edges.push((Point::new(col, -1), Point::DOWN));
+        edges.push((Point::new(col, grid.height()), Point::UP));
+    }
+
+    let mut visible = HashSet::<Point>::new();
+    for (edge, direction) in edges {
+        let mut tallest = -1;
+        for p in grid.ray(edge, direction) {
+            if grid[p] > tallest {
+                visible.insert(p);
+                tallest = grid[p];
+            }
+        }
+    }
+
+    visible.len()
+}
+
+//
+// Part 2
+//
+// I could brute force look at every position on the grid, and
+// compute its scenic score, then just take the max.  Since the full
+// input is 99x99, that might be reasonable.
+//
+// I can only guess that there is a more efficient solution.  Perhaps
+// starting with the tallest trees because they are more likely to
+// have a higher scenic score?  But might a shorter tree be in a
+// much better position (with lots of lower trees nearby), that ends
+// up with a better score?
+//
+fn part2(grid: &Grid<i8>) -> anyhow::Result<u32> {
+    grid.points()
+        .map(|p| scenic_score(grid, p))
+        .max().ok_or_else(|| anyhow!("there are no trees"))
+}
+
+fn scenic_score(grid: &Grid<i8>, tree: Point) -> u32 {
+    let height = grid[tree];
+
+    // Look in each direction, until the edge or a tree at least as tall
+    Point::ORTHOGONAL.iter().map(|&direction| {
+        let mut dir_score = 0;
+        for p in grid.ray(tree, direction) {
+            dir_score += 1;
+            if grid[p] >= height {
+                break;
+            }
+        }
+        dir_score
+    }).product()
+}
+
+fn parse_input(input: &str) -> Result<Grid<i8>, ParseError> {
+    // The element type is going to be i8 so that I can use -1 to
+    // represent off-grid values, and not have to cast back and forth
+    // to unsigned.
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as i8))
+}
+
+#[test]
+fn test_scenic_score() {
+    let grid = parse_input(include_str!("../../../fixtures/day08/example.txt")).unwrap();
+
+    assert_eq!(scenic_score(&grid, Point::new(2, 1)), 4);
+    assert_eq!(scenic_score(&grid, Point::new(2, 3)), 8);
+    assert_eq!(part2(&grid).unwrap(), 8);
+}
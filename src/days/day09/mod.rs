@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, ParseError};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(solve(input, 2)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(solve(input, 10)?.to_string())
+    }
+
+    // `size` moves of the head
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok((0..size).map(|_| {
+            format!("{} {}\n", rng.choose(&["U", "D", "L", "R"]), rng.range(1..=20))
+        }).collect())
+    }
+}
+
+//
+// Part 1
+//
+// Figuring out how to express the movement rules is the key here.
+// I think I need to keep a vertical and horizontal distance.
+// As long as each is at most 1, the head and tail are touching,
+// and the tail doesn't need to move.  If both are non-zero, then
+// the tail moves diagonally; otherwise, it moves in one dimension.
+//
+// The per-line movement instructions can move the head more than
+// one space.  I **think** I can move the head those multiple
+// positions all at once, and then have the tail catch up (as
+// opposed to simulating one square at a time).
+//
+// The final answer means we need to keep track of visited positions.
+//
+// Part 2 is the same, but with a longer rope.
+//
+fn solve(input: &str, rope_size: usize) -> Result<usize, ParseError> {
+    let mut visited: HashSet<(i32, i32)> = HashSet::new();
+    visited.insert((0, 0));
+    let mut rope = vec![(0,0); rope_size];
+
+    for line in lines(input) {
+        let (dir, count) = line.split_once(" ")?;
+        if !matches!(dir, "U" | "D" | "L" | "R") {
+            return Err(line.error(dir, "expected U, D, L or R"));
+        }
+        let count = line.parse::<u32>(count)?;
+        for _ in 0..count {
+            step_head(&mut rope, dir);
+            visited.insert(*rope.last().unwrap());
+        }
+    }
+
+    Ok(visited.len())
+}
+
+type Knot = (i32, i32);
+type Rope = Vec<Knot>;
+
+fn step_head(rope: &mut Rope, dir: &str) {
+    // Move the head
+    match dir {
+        "U" => rope[0].1 -= 1,
+        "D" => rope[0].1 += 1,
+        "L" => rope[0].0 -= 1,
+        "R" => rope[0].0 += 1,
+        _ => unreachable!("Invalid movement")
+    }
+
+    // Cause the rest of the knots to catch up as needed
+    for i in 0..(rope.len() - 1) {
+        let head = rope[i];
+        let tail = &mut rope[i+1];
+        // Move the next knot to catch up
+        if (head.0 - tail.0).abs() > 1 || (head.1 - tail.1).abs() > 1 {
+            tail.0 += (head.0 - tail.0).signum();
+            tail.1 += (head.1 - tail.1).signum();
+        }
+    }
+}
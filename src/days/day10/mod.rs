@@ -0,0 +1,94 @@
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input)?)
+    }
+
+    // `size` instructions, or more if that isn't enough for 240 cycles.
+    // X stays where the sprite can be seen.
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let mut result = String::new();
+        let (mut instructions, mut cycles, mut x) = (0, 0, 1);
+        while instructions < size || cycles < 240 {
+            if rng.chance(0.3) {
+                result.push_str("noop\n");
+                cycles += 1;
+            } else {
+                let v = rng.range((-15).max(-x) ..= 15.min(40 - x));
+                result.push_str(&format!("addx {v}\n"));
+                x += v;
+                cycles += 2;
+            }
+            instructions += 1;
+        }
+        Ok(result)
+    }
+}
+
+fn part1(input: &str) -> Result<i32, ParseError> {
+    let mut result = 0;
+    let x_values = run_program(input)?;
+    if x_values.len() < 220 {
+        return Err(truncated(input, "at least 220 cycles"));
+    }
+    for i in (0..=220).skip(20).step_by(40) {
+        result += i as i32 * x_values[i-1];
+    }
+    Ok(result)
+}
+
+//
+// Part 2
+//
+// The answer is the image on the CRT.  For my input, it shows
+// the letters: EZFPRAKL
+//
+fn part2(input: &str) -> Result<String, ParseError> {
+    let x_values = run_program(input)?;
+    if x_values.len() < 240 {
+        return Err(truncated(input, "at least 240 cycles"));
+    }
+    let mut x_values = x_values.into_iter();
+    let mut screen = String::new();
+
+    for _ in 0..6 {
+        for x in 0..40 {
+            if (x - x_values.next().unwrap()).abs() <= 1 {
+                screen.push('#');
+            } else {
+                screen.push('.');
+            }
+        }
+        screen.push('\n');
+    }
+    Ok(screen)
+}
+
+fn run_program(program: &str) -> Result<Vec<i32>, ParseError> {
+    let mut result = Vec::new();
+    let mut x = 1;
+    for line in lines(program) {
+        if line.text == "noop" {
+            result.push(x);
+        } else if let Some(v) = line.text.strip_prefix("addx ") {
+            result.push(x);
+            result.push(x);
+            x += line.parse::<i32>(v)?;
+        } else {
+            return Err(line.invalid("expected noop or addx"));
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,307 @@
+use std::collections::VecDeque;
+use anyhow::{bail, Context};
+use crate::{Param, Params, Rng, Solution, TopK};
+use crate::parse::{lines, truncated, Line, ParseError};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn part1(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part1(parse_input(input)?, params.get("rounds")?)?.to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part2(parse_input(input)?, params.get("part2_rounds")?)?.to_string())
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "rounds", default: "20", help: "Rounds of monkey business (part 1)" },
+            Param { name: "part2_rounds", default: "10000", help: "Rounds of monkey business (part 2)" },
+        ]
+    }
+
+    // `size` monkeys (from 2 to 8)
+    fn generate(&self, rng: &mut Rng, size: usize, params: &Params) -> anyhow::Result<String> {
+        generate(rng, size, params.get("rounds")?)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    // Each monkey is described by 6 lines, with blank lines between monkeys
+    let count = input.lines().filter(|line| line.starts_with("Monkey ")).count();
+    let mut lines = lines(input).filter(|line| !line.text.is_empty());
+    let mut monkeys = Vec::new();
+    while let Some(header) = lines.next() {
+        if !header.text.starts_with("Monkey ") {
+            return Err(header.invalid("expected \"Monkey n:\""));
+        }
+        monkeys.push(Monkey::parse(input, &mut lines, monkeys.len(), count)?);
+    }
+    Ok(monkeys)
+}
+
+fn part1(mut monkeys: Vec<Monkey>, rounds: u32) -> anyhow::Result<u64> {
+    let relax = |worry| worry / 3;
+    monkeys.many_rounds(rounds, relax)?;
+    Ok(monkeys.monkey_business())
+}
+
+fn part2(mut monkeys: Vec<Monkey>, rounds: u32) -> anyhow::Result<u64> {
+    let common_modulo = monkeys.iter().try_fold(1u64, |product, monkey| product.checked_mul(monkey.modulo))
+        .context("the product of the monkeys' divisors doesn't fit in 64 bits")?;
+    let relax = |worry| worry % common_modulo;
+    monkeys.many_rounds(rounds, relax)?;
+    Ok(monkeys.monkey_business())
+}
+
+#[derive(Clone)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn parse(line: &Line, s: &str) -> Result<Self, ParseError> {
+        if s == "old * old" {
+            Ok(Operation::Square)
+        } else if let Some(n) = s.strip_prefix("old * ") {
+            Ok(Operation::Multiply(line.parse(n)?))
+        } else if let Some(n) = s.strip_prefix("old + ") {
+            Ok(Operation::Add(line.parse(n)?))
+        } else {
+            Err(line.error(s, "unknown operation"))
+        }
+    }
+
+    // The new worry level, or None if it doesn't fit in 64 bits
+    fn apply(&self, worry: u64) -> Option<u64> {
+        match self {
+            Operation::Add(n) => worry.checked_add(*n),
+            Operation::Multiply(n) => worry.checked_mul(*n),
+            Operation::Square => worry.checked_mul(worry),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    modulo: u64,
+    is_divisible: usize,
+    not_divisible: usize,
+    inspected: u64,
+}
+
+impl Monkey {
+    //
+    // Parse the 5 lines after "Monkey n:".  `index` is this monkey's
+    // place in the list, and `count` the number of monkeys, so that we
+    // can check where items are thrown to.
+    //
+    fn parse<'a>(input: &str, lines: &mut impl Iterator<Item=Line<'a>>, index: usize, count: usize) -> Result<Self, ParseError> {
+        let mut next = |prefix: &str| -> Result<(Line<'a>, &'a str), ParseError> {
+            let line = lines.next().ok_or_else(|| truncated(input, format!("{prefix:?}")))?;
+            let rest = line.text.trim_start().strip_prefix(prefix)
+                .ok_or_else(|| line.invalid(format!("expected {prefix:?}")))?;
+            Ok((line, rest))
+        };
+
+        let (line, items_str) = next("Starting items: ")?;
+        let items = items_str.split(", ")
+            .map(|v| line.parse(v))
+            .collect::<Result<VecDeque<u64>, _>>()?;
+        let (line, op_str) = next("Operation: new = ")?;
+        let operation = Operation::parse(&line, op_str)?;
+        let (line, modulo_str) = next("Test: divisible by ")?;
+        let modulo = line.parse::<u64>(modulo_str)?;
+        if modulo == 0 {
+            return Err(line.error(modulo_str, "can't divide by zero"));
+        }
+        let mut destination = |prefix| -> Result<usize, ParseError> {
+            let (line, monkey_str) = next(prefix)?;
+            let monkey = line.parse::<usize>(monkey_str)?;
+            if monkey >= count {
+                return Err(line.error(monkey_str, format!("there are only {count} monkeys")));
+            }
+            if monkey == index {
+                return Err(line.error(monkey_str, "a monkey can't throw to itself"));
+            }
+            Ok(monkey)
+        };
+        let is_divisible = destination("If true: throw to monkey ")?;
+        let not_divisible = destination("If false: throw to monkey ")?;
+        Ok(Self { items, operation, modulo, is_divisible, not_divisible, inspected: 0 })
+    }
+
+    // The next item's worry level and where it is thrown, if there are
+    // any items left, or an error if the worry level gets too big
+    fn throw(&mut self, relax: impl Fn(u64)->u64) -> anyhow::Result<Option<(u64, usize)>>
+    {
+        let Some(worry) = self.items.pop_front() else {
+            return Ok(None);
+        };
+        let Some(worry) = self.operation.apply(worry).map(relax) else {
+            bail!("a worry level of {worry} went past 64 bits");
+        };
+        let destination = if worry % self.modulo == 0 {
+            self.is_divisible
+        } else {
+            self.not_divisible
+        };
+        self.inspected += 1;
+        Ok(Some((worry, destination)))
+    }
+}
+
+trait MonkeyBusiness {
+    fn one_round(&mut self, relax: impl Fn(u64)->u64) -> anyhow::Result<()>;
+    fn many_rounds(&mut self, num_rounds: u32, relax: impl Fn(u64)->u64) -> anyhow::Result<()>;
+    fn monkey_business(&self) -> u64;
+}
+impl MonkeyBusiness for Vec<Monkey> {
+    fn one_round(&mut self, relax: impl Fn(u64)->u64) -> anyhow::Result<()> {
+        for i in 0..self.len() {
+            while let Some((worry, destination)) = self[i].throw(&relax).with_context(|| format!("monkey {i}"))? {
+                self[destination].items.push_back(worry);
+            }
+        }
+        Ok(())
+    }
+
+    fn many_rounds(&mut self, num_rounds: u32, relax: impl Fn(u64)->u64) -> anyhow::Result<()> {
+        for round in 1..=num_rounds {
+            self.one_round(&relax).with_context(|| format!("round {round}"))?;
+        }
+        Ok(())
+    }
+
+    // The product of the two largest numbers of items inspected
+    fn monkey_business(&self) -> u64 {
+        let top = self.iter().map(|monkey| monkey.inspected).collect::<TopK<u64, u64, 2>>();
+        top.into_sorted_vec().iter().product()
+    }
+}
+
+//
+// Each monkey tests a different prime, so that in part 2 the product of
+// the primes, squared, fits in 64 bits.  At most one monkey squares the
+// worry level, and nobody throws to it, or the worry levels in part 1
+// would grow too fast.  Even so, a few monkeys that multiply and throw
+// to each other can overflow part 1's worry levels, so those inputs are
+// thrown away.  With enough rounds, almost all of them overflow, so give
+// up after a while.
+//
+const GENERATE_ATTEMPTS: usize = 1000;
+
+fn generate(rng: &mut Rng, size: usize, rounds: u32) -> anyhow::Result<String> {
+    for _ in 0..GENERATE_ATTEMPTS {
+        let input = generate_monkeys(rng, size);
+        if part1(parse_input(&input)?, rounds).is_ok() {
+            return Ok(input);
+        }
+    }
+    bail!("none of {GENERATE_ATTEMPTS} inputs kept part 1's worry levels within 64 bits for {rounds} rounds");
+}
+
+fn generate_monkeys(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 8);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let squarer = if count > 2 && rng.chance(0.5) { Some(rng.below(count)) } else { None };
+    let targets = (0..count).filter(|&i| Some(i) != squarer).collect::<Vec<_>>();
+
+    let mut result = Vec::new();
+    for (monkey, prime) in primes.iter().take(count).enumerate() {
+        let items = (0..rng.range(1..=6)).map(|_| rng.range(50..=99).to_string()).collect::<Vec<_>>();
+        let operation = if Some(monkey) == squarer {
+            "old * old".to_string()
+        } else if rng.chance(0.5) {
+            format!("old + {}", rng.range(1..=8))
+        } else {
+            format!("old * {}", rng.range(2..=19))
+        };
+        let mut throw = || loop {
+            let target = *rng.choose(&targets);
+            if target != monkey {
+                break target;
+            }
+        };
+        let (if_true, if_false) = (throw(), throw());
+        result.push(format!(
+            "Monkey {monkey}:\n  Starting items: {}\n  Operation: new = {operation}\n  Test: divisible by {prime}\n    If true: throw to monkey {if_true}\n    If false: throw to monkey {if_false}\n",
+            items.join(", ")
+        ));
+    }
+    result.join("\n")
+}
+
+#[test]
+fn test_rounds_part2() {
+    let input = include_str!("../../../fixtures/day11/example.txt");
+    let mut monkeys:Vec<Monkey> = parse_input(input).unwrap();
+    let common_modulo: u64 = monkeys.iter().map(|monkey| monkey.modulo).product();
+    let relax = |worry| worry % common_modulo;
+    monkeys.many_rounds(20, relax).unwrap();
+    assert_eq!(
+        monkeys
+            .iter()
+            .map(|monkey| monkey.inspected)
+            .collect::<Vec<_>>(),
+        vec![99, 97, 8, 103]
+    );
+    monkeys.many_rounds(980, relax).unwrap();
+    assert_eq!(
+        monkeys
+            .iter()
+            .map(|monkey| monkey.inspected)
+            .collect::<Vec<_>>(),
+        vec![5204, 4792, 199, 5192]
+    );
+    monkeys.many_rounds(9000, relax).unwrap();
+    assert_eq!(
+        monkeys
+            .iter()
+            .map(|monkey| monkey.inspected)
+            .collect::<Vec<_>>(),
+        vec![52166, 47830, 1938, 52013]
+    );
+    assert_eq!(monkeys.monkey_business(), 2713310158);
+}
+
+#[test]
+fn test_unknown_operation() {
+    let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old - 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+";
+    let error = parse_input(input).err().unwrap();
+    assert_eq!((error.line, error.column), (3, 20));
+    assert_eq!(error.to_string(), "line 3, column 20: unknown operation: \"old - 19\"");
+}
+
+#[test]
+fn test_bad_monkeys() {
+    let monkey = |index: usize, items: &str, operation: &str, target: usize| format!(
+        "Monkey {index}:\n  Starting items: {items}\n  Operation: new = {operation}\n  Test: divisible by 2\n    If true: throw to monkey {target}\n    If false: throw to monkey {target}\n"
+    );
+
+    // Squaring a worry level this big doesn't fit in 64 bits
+    let input = [monkey(0, "1", "old + 1", 1), monkey(1, "5000000000", "old * old", 0)].join("\n");
+    let error = part1(parse_input(&input).unwrap(), 20).unwrap_err();
+    assert_eq!(format!("{error:#}"), "round 1: monkey 1: a worry level of 5000000000 went past 64 bits");
+
+    // Throwing to itself would go on forever
+    let input = [monkey(0, "1", "old + 1", 1), monkey(1, "2", "old + 1", 1)].join("\n");
+    let error = parse_input(&input).err().unwrap();
+    assert_eq!((error.line, error.to_string().contains("a monkey can't throw to itself")), (12, true));
+}
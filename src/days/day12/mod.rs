@@ -0,0 +1,125 @@
+use anyhow::Context;
+use pathfinding::directed::{astar::astar, bfs::bfs};
+use crate::{Params, Rng, Solution};
+use crate::grid::{Grid, Point};
+use crate::parse::{truncated, ParseError};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+
+    // A heightmap `size` squares wide (at least 26) and a quarter as tall
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+fn part1(input: &str) -> anyhow::Result<i32> {
+    let input = parse_input(input)?;
+    let successors = |node: &Point| {
+        let node_height = input.heights[*node];
+        input.heights.neighbors4(*node)
+            .filter(|other| input.heights[*other] <= node_height + 1)
+            .map(|other| (other, 1))
+            .collect::<Vec<(Point, i32)>>()
+    };
+    let heuristic = |node: &Point| node.manhattan(&input.ending_point);
+    let success = |node: &Point| node == &input.ending_point;
+    let (_, steps) = astar(&input.starting_point, successors, heuristic, success)
+        .context("no path from S to E")?;
+    Ok(steps)
+}
+
+//
+// Part 2
+//
+// I'm guessing that we can path find from the ending coordinate, to
+// any square with a height of `a` (0).  We'll need to adjust the
+// logic in the neighbors callback, as well as the success callback.
+// Also, since we don't have a specific desintation in mind, I think
+// we need to use BFS.
+//
+fn part2(input: &str) -> anyhow::Result<usize> {
+    let input = parse_input(input)?;
+    let success = |node: &Point| input.heights[*node] == 'a' as u32;
+    let successors = |node: &Point| {
+        let node_height = input.heights[*node];
+        input.heights.neighbors4(*node)
+            .filter(|other| input.heights[*other] >= node_height - 1)
+            .collect::<Vec<Point>>()
+    };
+    let path = bfs(&input.ending_point, successors, success)
+        .context("no path from any 'a' to E")?;
+    Ok(path.len() - 1)
+}
+
+struct Input {
+    starting_point: Point,
+    ending_point: Point,
+    heights: Grid<u32>
+}
+
+fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let chars = Grid::parse(input, Some)?;
+    let starting_point = chars.position(|&ch| ch == 'S')
+        .ok_or_else(|| truncated(input, "a starting point 'S'"))?;
+    let ending_point = chars.position(|&ch| ch == 'E')
+        .ok_or_else(|| truncated(input, "an ending point 'E'"))?;
+    let heights = Grid::parse(input, |ch| match ch {
+        'S' => Some('a' as u32),
+        'E' => Some('z' as u32),
+        'a'..='z' => Some(ch as u32),
+        _ => None
+    })?;
+
+    Ok(Input { starting_point, ending_point, heights })
+}
+
+//
+// The height goes down by at most one per step away from E, until it
+// reaches 'a' at the far side, so there is always a way up to E.  Then
+// a few squares are dug out, like cliffs you can't climb out of, but
+// never next to each other, so there is always a way around them.
+//
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26) as i32;
+    let height = (width / 4).max(5);
+    let points = (0..height).flat_map(|y| (0..width).map(move |x| Point::new(x, y))).collect::<Vec<_>>();
+    // Far enough from some square for the hill to get down to 'a'
+    let end = loop {
+        let end = *rng.choose(&points);
+        if points.iter().any(|p| p.manhattan(&end) >= 25) {
+            break end;
+        }
+    };
+    let mut heights = points.iter()
+        .map(|p| (b'z' - p.manhattan(&end).min(25) as u8) as char)
+        .collect::<Vec<_>>();
+    let index = |p: Point| (p.y * width + p.x) as usize;
+
+    let lowest = points.iter().copied().filter(|&p| heights[index(p)] == 'a').collect::<Vec<_>>();
+    let start = *rng.choose(&lowest);
+    let mut dug = Vec::<Point>::new();
+    for &p in &points {
+        let near = |q: &Point| (p.x - q.x).abs() <= 1 && (p.y - q.y).abs() <= 1;
+        if heights[index(p)] > 'c' && rng.chance(0.05) && !near(&start) && !near(&end) && !dug.iter().any(near) {
+            heights[index(p)] = (heights[index(p)] as u8 - rng.range(2..=3) as u8) as char;
+            dug.push(p);
+        }
+    }
+    heights[index(start)] = 'S';
+    heights[index(end)] = 'E';
+
+    heights.chunks(width as usize).map(|row| row.iter().chain(&['\n']).collect::<String>()).collect()
+}
@@ -0,0 +1,257 @@
+use nom::{
+    branch::alt, bytes::complete::tag, combinator::cut, error::ErrorKind, multi::separated_list1,
+    sequence::{preceded, terminated}, IResult, Parser,
+};
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, truncated, Line, ParseError};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+
+    // `size` pairs of packets
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        fn list(rng: &mut Rng, depth: u32) -> Node {
+            Node::List((0..rng.range(0..=4)).map(|_| {
+                if depth == 0 || rng.chance(0.6) {
+                    Node::Number(rng.range(0..=10) as u32)
+                } else {
+                    list(rng, depth - 1)
+                }
+            }).collect())
+        }
+        let pairs = (0..size.max(1)).map(|_| format!("{}\n{}\n", list(rng, 4), list(rng, 4))).collect::<Vec<_>>();
+        Ok(pairs.join("\n"))
+    }
+}
+
+fn part1(input: &str) -> Result<usize, ParseError> {
+    let packets = parse_input(input)?;
+    if packets.len() % 2 != 0 {
+        return Err(truncated(input, "a second packet in the last pair"));
+    }
+    let pairs = packets.into_iter().tuples();
+    Ok(std::iter::zip(1.., pairs)
+        .filter_map(|(i, (left, right))| {
+            if left < right {
+                Some(i)
+            } else {
+                None
+            }
+        })
+        .sum())
+}
+
+fn part2(input: &str) -> Result<usize, ParseError> {
+    let mut packets = parse_input(input)?;
+    packets.push(divider(2));
+    packets.push(divider(6));
+    packets.sort();
+    let packet2 = packets
+        .iter()
+        .position(|packet| packet == &divider(2))
+        .unwrap();
+    let packet6 = packets
+        .iter()
+        .position(|packet| packet == &divider(6))
+        .unwrap();
+    Ok((packet2 + 1) * (packet6 + 1))
+}
+
+// A divider packet, like [[2]]
+fn divider(num: u32) -> Node {
+    Node::List(vec![Node::list(&num)])
+}
+
+// All of the packets, ignoring the blank lines between pairs
+fn parse_input(input: &str) -> Result<Vec<Node>, ParseError> {
+    lines(input)
+        .filter(|line| !line.text.is_empty())
+        .map(|line| parse_packet(&line))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Node {
+    List(Vec<Node>),
+    Number(u32),
+}
+
+impl Node {
+    fn list(num: &u32) -> Self {
+        Node::List(vec![Node::Number(*num)])
+    }
+}
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Node::Number(num) => {
+                write!(f, "{}", num)
+            }
+            Node::List(list) => {
+                let mut needs_comma = false;
+                write!(f, "[")?;
+                for node in list {
+                    if needs_comma {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", node)?;
+                    needs_comma = true;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use Node::*;
+        match (self, other) {
+            (Number(num), Number(other_num)) => num.cmp(other_num),
+            (List(list), List(other_list)) => list.cmp(other_list),
+            (Number(num), List(_)) => Node::list(num).cmp(other),
+            (List(_), Number(other_num)) => self.cmp(&Node::list(other_num))
+        }
+    }
+}
+
+//
+// Once we've seen a "[", any problem inside the list is a `cut` (fatal)
+// error, so that the error points at the problem rather than back at
+// the start of the list.
+//
+fn parse_node(input: &str) -> IResult<&str, Node> {
+    alt((
+        preceded(tag("["), cut(alt((
+            tag("]").map(|_| Vec::new()),
+            terminated(separated_list1(tag(","), cut(parse_node)), tag("]"))
+        ))))
+            .map(Node::List),
+        nom::character::complete::u32.map(Node::Number),
+    ))(input)
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(1, s);
+        match parse_node(s) {
+            Ok(("", node)) => Ok(node),
+            Ok((remaining, _)) => Err(line.error(remaining, "unexpected text after packet")),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                let message = match e.code {
+                    ErrorKind::Digit => "expected '[' or a number",
+                    _ => "expected ',' or ']'"
+                };
+                Err(line.error(e.input, message))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
+        }
+    }
+}
+
+fn parse_packet(line: &Line) -> Result<Node, ParseError> {
+    line.parse_nested(line.text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Node::*;
+
+    fn packet(s: &str) -> Node {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_list_of_five_numbers() {
+        let line = "[1,2,3,4,5]";
+        let node = packet(line);
+        assert_eq!(
+            node,
+            List(vec![Number(1), Number(2), Number(3), Number(4), Number(5)])
+        );
+        assert_eq!(node.to_string(), line);
+    }
+    
+    #[test]
+    fn test_parse_nested_list_of_numbers() {
+        let line = "[[1],[2,3,4]]";
+        let node = packet(line);
+        assert_eq!(
+            node,
+            List(vec![
+                List(vec![Number(1)]),
+                List(vec![Number(2), Number(3), Number(4)])
+            ])
+        );
+        assert_eq!(node.to_string(), line);
+    }
+    
+    #[test]
+    fn test_parse_nested_empty_lists() {
+        use Node::*;
+        let line = "[[[]],[]]";
+        let node = packet(line);
+        assert_eq!(
+            node,
+            List(vec![
+                List(vec![
+                    List(vec![])
+                ]),
+                List(vec![])
+            ])
+        );
+        assert_eq!(node.to_string(), line);
+    }
+    
+    #[test]
+    fn test_cmp_numbers() {
+        assert!(Node::Number(3) < Node::Number(4));
+        assert!(Node::Number(4) == Node::Number(4));
+        assert!(Node::Number(5) > Node::Number(4));
+    }
+    
+    #[test]
+    fn test_cmp_lists() {
+        assert!(packet("[1,1,3,1,1]") < packet("[1,1,5,1,1]"));
+        assert!(packet("[[1],[2,3,4]]") < packet("[[1],4]"));
+        assert!(packet("[9]") > packet("[[8,7,6]]"));
+        assert!(packet("[[4,4],4,4]") < packet("[[4,4],4,4,4]"));
+        assert!(packet("[7,7,7,7]") > packet("[7,7,7]"));
+        assert!(packet("[]") < packet("[3]"));
+        assert!(packet("[[[]]]") > packet("[[]]"));
+        assert!(
+            packet("[1,[2,[3,[4,[5,6,7]]]],8,9]") > packet("[1,[2,[3,[4,[5,6,0]]]],8,9]")
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_input("[1,2]\n[1,[2,x]]\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 7, "x]]"));
+        assert_eq!(error.message, "expected '[' or a number");
+        let error = parse_input("[1,2]\n[1 2]\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.message.as_str()), (2, 3, "expected ',' or ']'"));
+    }
+    
+}
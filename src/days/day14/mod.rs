@@ -0,0 +1,190 @@
+use anyhow::{anyhow, bail};
+use crate::{Animation, Params, Rng, Solution};
+use crate::grid::{Point, SparseGrid};
+use crate::parse::{lines, truncated, Line, ParseError};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(&mut parse_input(input)?, |_| {})?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(&mut parse_input(input)?, |_| {})?.to_string())
+    }
+
+    // A frame each time a unit of sand settles
+    fn visualize(&self, part: u8, input: &str, _params: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+        let mut cells = parse_input(input)?;
+        let on_settle = |cells: &SparseGrid<Cell>| animation.step(|| render(cells));
+        match part {
+            1 => part1(&mut cells, on_settle)?,
+            2 => part2(&mut cells, on_settle)?,
+            _ => bail!("Day 14 has no part {part}"),
+        };
+        animation.last(|| render(&cells));
+        Ok(())
+    }
+
+    // `size` paths of rock
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok((0..size.max(1)).map(|_| {
+            let mut p = Point::new(rng.range(460..=540) as i32, rng.range(13..=170) as i32);
+            let mut path = vec![format!("{},{}", p.x, p.y)];
+            let horizontal = rng.chance(0.5);
+            for i in 0..rng.range(1..=5) {
+                let length = rng.range(1..=10) as i32 * if rng.chance(0.5) { 1 } else { -1 };
+                if (i % 2 == 0) == horizontal {
+                    p.x += length;
+                } else {
+                    p.y = (p.y + length).max(1);
+                }
+                path.push(format!("{},{}", p.x, p.y));
+            }
+            path.join(" -> ") + "\n"
+        }).collect())
+    }
+}
+
+fn part1(cells: &mut SparseGrid<Cell>, mut on_settle: impl FnMut(&SparseGrid<Cell>)) -> anyhow::Result<usize> {
+    // Find the greatest Y value in `cells`
+    let max_y = lowest_rock(cells)?;
+
+    // Start dropping units of sand
+    let mut done = false;
+    while !done {
+        // Starting position of sand
+        let mut sand = SOURCE;
+
+        // Move until it settles or falls off the bottom
+        while sand.y <= max_y {
+            if let Some(next) = fall(cells, sand) {
+                sand = next;
+            } else {
+                // Sand settles here
+                cells.insert(sand, Cell::Sand);
+                on_settle(cells);
+                break;
+            }
+        }
+        if sand.y > max_y {
+            // There was no place to settle, so we must be done
+            done = true;
+        }
+    }
+
+    Ok(cells.values().filter(|v| v == &&Cell::Sand).count())
+}
+
+fn part2(cells: &mut SparseGrid<Cell>, mut on_settle: impl FnMut(&SparseGrid<Cell>)) -> anyhow::Result<usize> {
+    // Find the greatest Y value in `cells`
+    let max_y = lowest_rock(cells)?;
+
+    // Start dropping units of sand
+    while !cells.contains(SOURCE) {
+        let mut sand = SOURCE;
+
+        // Move until it settles
+        loop {
+            if sand.y > max_y {
+                // Infinite floor below, so sand settles here
+                cells.insert(sand, Cell::Sand);
+                break;
+            } else if let Some(next) = fall(cells, sand) {
+                sand = next;
+            } else {
+                // Sand settles here
+                cells.insert(sand, Cell::Sand);
+                break;
+            }
+        }
+        on_settle(cells);
+    }
+
+    Ok(cells.values().filter(|v| v == &&Cell::Sand).count())
+}
+
+// The greatest Y value in `cells` (the lowest rock, since Y grows downward)
+fn lowest_rock(cells: &SparseGrid<Cell>) -> anyhow::Result<i32> {
+    let (_min, max) = cells.bounds().ok_or_else(|| anyhow!("there is no rock"))?;
+    Ok(max.y)
+}
+
+// Where the sand comes from
+const SOURCE: Point = Point::new(500, 0);
+
+//
+// Where a unit of sand at `sand` moves next: straight down if that is air,
+// otherwise down and left, otherwise down and right.  None if all three
+// are blocked, so the sand settles where it is.
+//
+fn fall(cells: &SparseGrid<Cell>, sand: Point) -> Option<Point> {
+    [Point::DOWN, Point::DOWN + Point::LEFT, Point::DOWN + Point::RIGHT]
+        .into_iter()
+        .map(|direction| sand + direction)
+        .find(|p| !cells.contains(*p))
+}
+
+// Rock is '#', sand is 'o', and the source of the sand is '+'
+fn render(cells: &SparseGrid<Cell>) -> String {
+    let (min, max) = cells.bounds().unwrap_or((SOURCE, SOURCE));
+    let mut result = String::new();
+    for y in SOURCE.y.min(min.y) ..= max.y {
+        for x in SOURCE.x.min(min.x) ..= SOURCE.x.max(max.x) {
+            let p = Point::new(x, y);
+            result.push(match cells.get(p) {
+                Some(Cell::Rock) => '#',
+                Some(Cell::Sand) => 'o',
+                None if p == SOURCE => '+',
+                None => '.',
+            });
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[derive(PartialEq, Eq)]
+enum Cell {
+    Rock,
+    Sand
+}
+
+fn parse_input(input: &str) -> Result<SparseGrid<Cell>, ParseError> {
+    let mut cells = SparseGrid::new();
+    for line in lines(input) {
+        fill_path(&mut cells, &line)?;
+    }
+    if cells.is_empty() {
+        return Err(truncated(input, "a path of rock"));
+    }
+    Ok(cells)
+}
+
+fn fill_path(cells: &mut SparseGrid<Cell>, line: &Line) -> Result<(), ParseError> {
+    let mut coords = line.text.split(" -> ");
+    let mut p = parse_coord(line, coords.next().unwrap())?;
+    cells.insert(p, Cell::Rock);
+    for point in coords {
+        let next = parse_coord(line, point)?;
+        if next.x != p.x && next.y != p.y {
+            return Err(line.error(point, "path must be horizontal or vertical"));
+        }
+        let direction = Point::new((next.x - p.x).signum(), (next.y - p.y).signum());
+        while p != next {
+            p += direction;
+            cells.insert(p, Cell::Rock);
+        }
+    }
+    Ok(())
+}
+
+fn parse_coord(line: &Line, coord: &str) -> Result<Point, ParseError> {
+    let (x, y) = line.split_piece(coord, ",")?;
+    Ok(Point::new(line.parse(x)?, line.parse(y)?))
+}
@@ -0,0 +1,318 @@
+use std::{fmt::Debug, ops::{Add, Sub}, str::FromStr, collections::HashSet};
+use anyhow::{anyhow, bail};
+use crate::{Param, Params, RangeSet, Rng, Solution, Strategy};
+use crate::parse::{lines, truncated, Line, ParseError};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn part1(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        let input = parse_input(input)?;
+        Ok(part1(&input, params.get("row")?).to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        let input = parse_input(input)?;
+        Ok(part2_line_intersect(&input, bound(params)?)?.to_string())
+    }
+
+    // Brute force gets the same answer, but much more slowly.
+    fn strategies(&self) -> &'static [Strategy] {
+        &[
+            Strategy {
+                part: 2, name: "line_intersect", slow: false,
+                help: "Check where the lines just outside the sensors' ranges cross",
+                solve: |input, params| Ok(part2_line_intersect(&parse_input(input)?, bound(params)?)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "brute_force", slow: true,
+                help: "Look for a gap in every row",
+                solve: |input, params| Ok(part2_brute_force(&parse_input(input)?, bound(params)?)?.to_string()),
+            },
+        ]
+    }
+
+    // The example uses row 10, and a bound of 20.
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "row", default: "2000000", help: "Row to count non-beacon positions in (part 1)" },
+            Param { name: "bound", default: "4000000", help: "Largest x and y for the distress beacon (part 2)" },
+        ]
+    }
+
+    // `size` sensors (plus four more), with the distress beacon within `bound`
+    fn generate(&self, rng: &mut Rng, size: usize, params: &Params) -> anyhow::Result<String> {
+        Ok(generate(rng, size, bound(params)?.into()))
+    }
+}
+
+fn part1(pairs: &[(Point<i32>, Point<i32>)], y: i32) -> i32 {
+    let mut ranges = RangeSet::new();
+    for (sensor, beacon) in pairs.iter() {
+        let dist = sensor.distance_to(beacon);
+        let dist_to_y = (sensor.1 - y).abs();
+        if dist >= dist_to_y {
+            // There is at least one point on line `y` that is within `dist`
+            let min_x = sensor.0 - (dist - dist_to_y);
+            let max_x = sensor.0 + (dist - dist_to_y);
+            ranges.insert(min_x ..= max_x);
+        }
+    }
+
+    // Now remove any beacons on line `y`
+    for (_, beacon) in pairs.iter() {
+        if beacon.1 == y {
+            ranges.remove(beacon.0);
+        }
+    }
+
+    ranges.len()
+}
+
+fn part1_range_set(pairs: &[(Point<i32>, Point<i32>)], y: i32) -> RangeSet<i32> {
+    let mut ranges = RangeSet::new();
+    for (sensor, beacon) in pairs.iter() {
+        let dist = sensor.distance_to(beacon);
+        let dist_to_y = (sensor.1 - y).abs();
+        if dist >= dist_to_y {
+            // There is at least one point on line `y` that is within `dist`
+            let min_x = sensor.0 - (dist - dist_to_y);
+            let max_x = sensor.0 + (dist - dist_to_y);
+            ranges.insert(min_x ..= max_x);
+        }
+    }
+
+    // Now add any beacons on line `y`, which can't be the location
+    // of the distress signal.
+    for (_, beacon) in pairs.iter() {
+        if beacon.1 == y {
+            ranges.insert(beacon.0);
+        }
+    }
+
+    ranges
+}
+
+// The search area runs from 0 to `bound`, so it can't be negative
+fn bound(params: &Params) -> anyhow::Result<i32> {
+    let bound = params.get("bound")?;
+    if bound < 0 {
+        bail!("the bound must be at least 0, not {bound}");
+    }
+    Ok(bound)
+}
+
+fn part2_brute_force(input: &[(Point<i32>, Point<i32>)], upper_y: i32) -> anyhow::Result<i64> {
+    // How do I solve this?  I can't try all 4,000,000 * 4,000,000
+    // possible coordinates.
+
+    // Let's try brute force.  Let's apply part1 to all possible Y
+    // values and see which one has a gap in its coverage.
+    for y in 0 ..= upper_y {
+        let covered = part1_range_set(input, y);
+        if let Some(gap) = covered.first_gap_in(0..=upper_y) {
+            let x = gap.start;
+            return Ok(4_000_000i64 * (x as i64) + (y as i64));
+        }
+    }
+    bail!("found no place for the distress beacon");
+}
+
+//
+// With Manhattan distance, a fixed distance (what would be a circle)
+// ends up being a square rotated by 45º relative to the axes.
+// The solution to part 2 must be a point that has a distance of 1 more
+// than a sensor's radius for at least 4 sensors.
+//
+// The approach we will use is to find the lines that are at a distance
+// of 1 more than any sensor's radius (i.e. just outside the sensor's
+// area).  Find all of the points that are an intersection of two such
+// lines; these are candidates for the solution.  Of those, find the one
+// that is outside the range of all sensors.
+//
+// Note that all of these lines have a slope of +/- 1.
+// We can look for intersections between a slope=1 and slope=-1 line.
+//
+// The point-slope form of a line is:
+//      (y - y1) = m (x - x1) =>
+//      y = m(x - x1) + y1
+//
+// Taking two lines of slope 1 and -1, and solving for x, we get:
+//      x = (x2 + y2 + x1 - y1) / 2
+// Then substitute into the point-slope equation with m=1 to find y:
+//      y = x - x1 + y1
+//
+// Which points shall we use?  It's convenient to pick points at opposite
+// corners of the radius-plus-one square.  Lines with slopes +1 and -1
+// passing through those two points form the four lines bounding the
+// sensor.  For example, consider a sensor of radius 3:
+//
+//          x
+//         xxx
+//        xxxxx
+//      PxxxSxxxQ
+//        xxxxx
+//         xxx
+//          x
+//
+// We'll pick the points marked `P` and `Q`.  To find candidate points, we
+// iterate over pairs of points, associate slope 1 with the first point and
+// slope -1 with the second point, and find the intersection using the formulas
+// above.
+//
+fn part2_line_intersect(input: &[(Point<i32>, Point<i32>)], upper_y: i32) -> anyhow::Result<i64> {
+    // Build a vector of corner points (P and Q, above)
+    let mut corners = Vec::with_capacity(input.len() * 2);
+    for (sensor, beacon) in input {
+        let dist = sensor.distance_to(beacon) + 1;
+        corners.push(Point(sensor.0 - dist, sensor.1));
+        corners.push(Point(sensor.0 + dist, sensor.1));
+    }
+
+    // Build a vector of candidate solutions
+    let mut candidates = HashSet::new();
+    for Point(x1,y1) in corners.iter() {
+        for Point(x2,y2) in corners.iter() {
+            let x = (x2 + y2 + x1 - y1) / 2;
+            let y = x - x1 + y1;
+            if x >= 0 && x <= upper_y && y >= 0 && y <= upper_y {
+                candidates.insert(Point(x,y));
+            }
+        }
+    }
+
+    // Find the one solution
+    let Point(x,y) = candidates.iter().find(|p| {
+        p.0 >= 0 && p.0 <= upper_y && p.1 >= 0 && p.1 <= upper_y &&
+        input.iter().all(|(sensor, beacon)| {
+            sensor.distance_to(p) > sensor.distance_to(beacon)
+        })
+    }).ok_or_else(|| anyhow!("found no place for the distress beacon"))?;
+    Ok(4_000_000i64 * (*x as i64) + (*y as i64))
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Point<T>(T, T);
+
+impl<T> Point<T>
+where T: Copy + Sub<Output=T> + Add<Output=T> + Ord
+{
+    fn distance_to(&self, other: &Self) -> T {
+        let min0 = self.0.min(other.0);
+        let max0 = self.0.max(other.0);
+        let min1 = self.1.min(other.1);
+        let max1 = self.1.max(other.1);
+        (max0 - min0) + (max1 - min1)
+    }
+}
+
+// A sensor and its closest beacon
+type SensorBeacon = (Point<i32>, Point<i32>);
+
+fn parse_input(input: &str) -> Result<Vec<SensorBeacon>, ParseError> {
+    let pairs = lines(input).map(|line| parse_line(&line)).collect::<Result<Vec<_>, _>>()?;
+    if pairs.is_empty() {
+        return Err(truncated(input, "a sensor"));
+    }
+    Ok(pairs)
+}
+
+//
+// Parse a line that looks like:
+//  Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+// and return the Point for the sensor and beacon (in that order)
+//
+fn parse_line<T: FromStr>(line: &Line) -> Result<(Point<T>, Point<T>), ParseError> {
+    let rest = line.strip_prefix("Sensor at x=")?;
+    let (x, rest) = line.split_piece(rest, ", y=")?;
+    let (y, rest) = line.split_piece(rest, ": closest beacon is at x=")?;
+    let sensor = Point(line.parse(x)?, line.parse(y)?);
+
+    let (x, y) = line.split_piece(rest, ", y=")?;
+    let beacon = Point(line.parse(x)?, line.parse(y)?);
+
+    Ok((sensor, beacon))
+}
+
+
+//
+// Pick the distress beacon first.  Four sensors diagonally away from it,
+// which reach to just short of it, cover the rest of the search area.
+// The other sensors are anywhere, and don't reach the distress beacon.
+//
+fn generate(rng: &mut Rng, size: usize, bound: i64) -> String {
+    let (x, y) = (rng.range(0..=bound), rng.range(0..=bound));
+    let distance = |(x1, y1): (i64, i64), (x2, y2): (i64, i64)| (x1 - x2).abs() + (y1 - y2).abs();
+
+    let mut sensors = Vec::new();
+    let k = bound + rng.range(1..=1000);
+    for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+        let sensor = (x + dx * k, y + dy * k);
+        sensors.push((sensor, (sensor.0 + dx * (2 * k - 1), sensor.1)));
+    }
+    while sensors.len() < size + 4 {
+        let sensor = (rng.range(0..=bound), rng.range(0..=bound));
+        let reach = distance(sensor, (x, y));
+        if reach < 2 {
+            continue;
+        }
+        let radius = rng.range(reach / 2 ..= reach - 1).max(1);
+        let dx = rng.range(-radius..=radius);
+        let dy = (radius - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        sensors.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+    }
+    rng.shuffle(&mut sensors);
+
+    sensors.into_iter().map(|((sx, sy), (bx, by))| {
+        format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/day15/example.txt");
+    
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            parse_line(&Line::new(1, "Sensor at x=2, y=18: closest beacon is at x=-2, y=15")),
+            Ok((Point(2,18), Point(-2,15)))
+        );
+        assert_eq!(
+            parse_line(&Line::new(1, "Sensor at x=20, y=14: closest beacon is at x=25, y=17")),
+            Ok((Point(20,14), Point(25,17)))
+        );
+        let error = parse_line::<i32>(&Line::new(4, "Sensor at x=20, y=14: closest beacon is at x=2five, y=17"))
+            .unwrap_err();
+        assert_eq!(error.to_string(), "line 4, column 46: expected a number: \"2five\"");
+    }
+    
+
+    #[test]
+    fn part2_example() {
+        let input = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part2_brute_force(&input, 20).unwrap(), 56000011);
+        assert_eq!(part2_line_intersect(&input, 20).unwrap(), 56000011);
+    }
+
+    #[test]
+    fn part2_negative_bound() {
+        let params = Params::new(Day15.params(), [("bound", "-5")]).unwrap();
+        assert_eq!(Day15.part2(EXAMPLE, &params).unwrap_err().to_string(), "the bound must be at least 0, not -5");
+    }
+
+    #[test]
+    fn part2_no_gap() {
+        // One sensor that reaches the whole search area
+        let input = parse_input("Sensor at x=10, y=10: closest beacon is at x=10, y=40\n").unwrap();
+        for result in [part2_brute_force(&input, 20), part2_line_intersect(&input, 20)] {
+            assert_eq!(result.unwrap_err().to_string(), "found no place for the distress beacon");
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use std::collections::{hash_map::Entry, HashMap};
+use std::ops::{Add, Sub};
+use anyhow::anyhow;
+use pathfinding::prelude::{dijkstra,dijkstra_all};
+use crate::{Params, Rng, Solution, Strategy};
+use crate::search::SearchStats;
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+
+    fn strategies(&self) -> &'static [Strategy] {
+        &[
+            Strategy {
+                part: 2, name: "subsets", slow: false,
+                help: "Best pair of disjoint sets of opened valves",
+                solve: |input, _| Ok(part2(input)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "greedy", slow: false,
+                help: "The person opens what they can, then the elephant (not always optimal)",
+                solve: |input, _| Ok(part2b(input)?.to_string()),
+            },
+        ]
+    }
+
+    // `size` valves (at most 64), a quarter of them with flow
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+//
+// Part 1
+//
+// The only "interesting" locations are where the valves have non-zero
+// flow rates.  Our task is to find a path from valve to valve, turning
+// each valve on when we get to it, such that the resulting flow is
+// maximized.
+//
+// Let's use Dijkstra's shortest path algorithm.
+//
+// But Dijkstra's wants to minimize some cost.  So, what is our "cost"?
+// I'm using the "opportunity cost" of unrealized flow from closed valves.
+// Cost is the sum of those closed valves' flow rates times elapsed time.
+//
+// To simplify the number of states we must examine, we need to eliminate
+// locations whose flow rate is zero.  But that also means we need to
+// calculate the time to move between valves using the locations with
+// zero flow.  That is all pairs shortest paths.
+//
+fn part1(input: &str) -> anyhow::Result<u32> {
+    #[derive(PartialEq, Eq, Hash, Clone, Debug)]
+    struct State {
+        location: ValveID,       // Our current location
+        minutes: u32,           // Minutes left
+        closed: BitSet,            // Bitmap of closed valves
+    }
+    let (aa_id, valves) = parse_input(input)?;
+    let paths = all_pairs_shortest_paths(&valves);
+    let closed = valves.iter().enumerate().filter_map(|(id, valve)| {
+        if valve.flow > 0 {
+            Some(id as u32)
+        } else {
+            None
+        }
+    }).collect::<BitSet>();
+    let start = State { location: aa_id, minutes: 30, closed };
+    let stats = SearchStats::new(module_path!(), "part 1");
+    let success = |state: &State| state.minutes == 0;
+    let successors = |state: &State| -> Vec<(State, u32)> {
+        stats.pop();
+        let mut result = Vec::new();
+        
+        // What is the total flow rate of all closed valves?
+        let total_flow: u32 = state.closed.into_iter().map(|id| valves[id as usize].flow).sum();
+
+        // Consider each of the remaining closed valves
+        for id in state.closed.into_iter() {
+            // Find out how much time to get to that valve and open it
+            let Some(distance) = paths.get(&(state.location, id)) else {
+                stats.prune("unreachable");
+                continue;
+            };
+            let time = distance + 1;
+            if time < state.minutes {
+                let closed = state.closed - id; // Open valve #`id`
+                result.push((
+                    State{ location: id, minutes: state.minutes-time, closed },
+                    time * total_flow
+                ));
+            } else {
+                stats.prune("out of time");
+            }
+        }
+
+        // If there wasn't time to close any valves, then the only next state is
+        // that we're done.  Don't forget there is a cost!  The actual location
+        // and list of closed valves don't matter.
+        if result.is_empty() {
+            result.push((
+                State{ location: 0, minutes: 0, closed: state.closed },
+                state.minutes * total_flow
+            ));
+        }
+        for (state, _) in &result {
+            stats.push_state(state);
+        }
+        result
+    };
+
+    let (_, cost) = dijkstra(&start, successors, success).ok_or_else(no_plan)?;
+    stats.finish();
+
+    // The answer to part 1 is the total flow that _did_ happen.
+    // So that is the maximum possible flow (if all valves had been open
+    // at time zero) minus the flow we missed out on while moving from
+    // valve to valve.
+    let max_flow = valves.iter().map(|valve| valve.flow * 30).sum::<u32>();
+    Ok(max_flow - cost)
+}
+
+//
+// Part 2
+//
+// The person and the elephant open disjoint sets of valves.  So find
+// the most pressure that one of them could release by opening each set
+// of valves in 26 minutes (a depth-first search over the orders they
+// could be opened in), and then the best pair of disjoint sets.
+//
+fn part2(input: &str) -> anyhow::Result<u32> {
+    let (aa_id, valves) = parse_input(input)?;
+    let paths = all_pairs_shortest_paths(&valves);
+    let useful = valves.iter().enumerate().filter_map(|(id, valve)| {
+        if valve.flow > 0 {
+            Some(id as u32)
+        } else {
+            None
+        }
+    }).collect::<BitSet>();
+
+    // The most pressure released by opening exactly this set of valves
+    // (A cache hit is another order of opening a set of valves seen before.)
+    let stats = SearchStats::new(module_path!(), "part 2 subsets");
+    let mut best = HashMap::<BitSet, u32>::new();
+    let mut stack = vec![(aa_id, 26, BitSet::default(), 0)];
+    while let Some((location, minutes, opened, released)) = stack.pop() {
+        stats.pop();
+        match best.entry(opened) {
+            Entry::Occupied(mut entry) => {
+                stats.cache_hit();
+                *entry.get_mut() = released.max(*entry.get());
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(released);
+            }
+        }
+        for id in useful.into_iter().filter(|&id| !opened.contains(id)) {
+            let Some(distance) = paths.get(&(location, id)) else {
+                stats.prune("unreachable");
+                continue;
+            };
+            let time = distance + 1;
+            if time < minutes {
+                let minutes = minutes - time;
+                stack.push((id, minutes, opened + id, released + minutes * valves[id as usize].flow));
+                stats.push();
+            } else {
+                stats.prune("out of time");
+            }
+        }
+    }
+    stats.finish();
+
+    let best = best.into_iter().collect::<Vec<_>>();
+    let mut result = 0;
+    for (i, &(person, person_flow)) in best.iter().enumerate() {
+        for &(elephant, elephant_flow) in &best[i..] {
+            if person.0 & elephant.0 == 0 {
+                result = result.max(person_flow + elephant_flow);
+            }
+        }
+    }
+    Ok(result)
+}
+
+//
+// The idea here is to see which valves the person could open in the allotted
+// time (essentially part 1), then remove those valves from consideration and
+// run again for the elephant.  The answer is the total flow from both runs.
+//
+fn part2b(input: &str) -> anyhow::Result<u32> {
+    #[derive(PartialEq, Eq, Hash, Clone, Debug)]
+    struct State {
+        location: ValveID,
+        minutes: u32,
+        closed: BitSet
+    }
+    let (aa_id, valves) = parse_input(input)?;
+    let paths = all_pairs_shortest_paths(&valves);
+    let closed = valves.iter().enumerate().filter_map(|(id, valve)| {
+        if valve.flow > 0 {
+            Some(id as u32)
+        } else {
+            None
+        }
+    }).collect::<BitSet>();
+    let initial = State { location: aa_id, minutes: 26, closed };
+    let stats = SearchStats::new(module_path!(), "part 2 greedy");
+    let success = |state: &State| state.minutes == 0;
+    let successors = |state: &State| -> Vec<(State, u32)> {
+        stats.pop();
+        let mut result = Vec::new();
+        
+        // What is the total flow rate of all closed valves?
+        let total_flow: u32 = state.closed.into_iter().map(|id| valves[id as usize].flow).sum();
+
+        // Consider each of the remaining closed valves
+        for id in state.closed.into_iter() {
+            // Find out how much time to get to that valve and open it
+            let Some(distance) = paths.get(&(state.location, id)) else {
+                stats.prune("unreachable");
+                continue;
+            };
+            let time = distance + 1;
+            if time < state.minutes {
+                let closed = state.closed - id; // Open valve #`id`
+                result.push((
+                    State{ location: id, minutes: state.minutes-time, closed },
+                    time * total_flow
+                ));
+            } else {
+                stats.prune("out of time");
+            }
+        }
+
+        // If there wasn't time to close any valves, then the only next state is
+        // that we're done.  Don't forget there is a cost!  The actual location
+        // and list of closed valves don't matter.
+        if result.is_empty() {
+            result.push((
+                State{ location: 0, minutes: 0, closed: state.closed },
+                state.minutes * total_flow
+            ));
+        }
+        for (state, _) in &result {
+            stats.push_state(state);
+        }
+        result
+    };
+
+    //
+    // Let the person do their best to open valves.  Like part 1, with less time.
+    //
+    let max_flow = valves.iter().map(|valve| valve.flow * 26).sum::<u32>();
+    let (path, cost) = dijkstra(&initial, successors, success).ok_or_else(no_plan)?;
+    let person_flow = max_flow - cost;
+    
+    //
+    // Let the elephant open as many of the remaining valves as possible
+    //
+    let closed_valves = path[path.len() - 2].closed;
+    let elephant_max_flow: u32 = closed_valves.into_iter()
+        .map(|id| valves[id as usize].flow * 26)
+        .sum();
+    let initial = State { location: aa_id, minutes: 26, closed: closed_valves };
+    let (_path, elephant_cost) = dijkstra(&initial, successors, success).ok_or_else(no_plan)?;
+    stats.finish();
+    let elephant_flow = elephant_max_flow - elephant_cost;
+
+    Ok(person_flow + elephant_flow)
+}
+
+// (There is always a plan, if only to stand still, so this shouldn't happen.)
+fn no_plan() -> anyhow::Error {
+    anyhow!("found no plan for opening the valves")
+}
+
+//
+// The number of minutes from each valve to every other.  Valves that
+// can't be reached from a valve have no entry.
+//
+fn all_pairs_shortest_paths(input: &[Valve; 64]) -> HashMap<(ValveID, ValveID), u32> {
+    let mut result = HashMap::new();
+
+    // We're going to do this the expensive way: via dijkstra_all
+    for source in 0..input.len() {
+        let successors = |node: &ValveID| -> Vec<(ValveID, u32)> {
+            input[*node as usize].neighbors.into_iter().map(|name| (name, 1)).collect()
+        };
+        let paths = dijkstra_all(&(source as ValveID), successors);
+        result.insert((source as ValveID, source as ValveID), 0);
+        for (destination, (_, cost)) in paths.iter() {
+            result.insert((source as ValveID, *destination), *cost);
+        }
+    }
+
+    result
+}
+
+type ValveID = u32;
+#[derive(Debug, Default, Clone, Copy)]
+struct Valve {
+    flow: u32,
+    neighbors: BitSet
+}
+
+//
+// Parse lines like:
+//  Valve BB has flow rate=13; tunnels lead to valves CC, AA
+//  Valve HH has flow rate=22; tunnel leads to valve GG
+//
+fn parse_input(input: &str) -> Result<(ValveID, [Valve; 64]), ParseError> {
+    // First pass: get the name, flow rate and neighbor names of each valve
+    let mut parsed = Vec::new();
+    for line in lines(input) {
+        let rest = line.strip_prefix("Valve ")?;
+        let (name, rest) = line.split_piece(rest, " has flow rate=")?;
+        let (flow, rest) = line.split_piece(rest, "; ")?;
+        let flow = line.parse::<u32>(flow)?;
+
+        // Darned input has both "lead to valves " and "leads to valve ".
+        let neighbors = rest.strip_prefix("tunnels lead to valves ")
+            .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| line.error(rest, "expected \"tunnels lead to valves\""))?;
+        parsed.push((line, name, flow, neighbors));
+    }
+
+    // Build a mapping from textual valve name to ValveID.
+    // The valves are stored in a bit set, so there can only be 64.
+    let mut valve_names = HashMap::<&str, ValveID>::new();
+    for (id, (line, name, _, _)) in parsed.iter().enumerate() {
+        if id >= 64 {
+            return Err(line.invalid("too many valves (at most 64)"));
+        }
+        valve_names.insert(name, id as ValveID);
+    }
+
+    let mut result = [Valve::default(); 64];
+    for (id, (line, _, flow, neighbors)) in parsed.into_iter().enumerate() {
+        // Get the IDs of neighbor valves.
+        let neighbors = neighbors
+            .split(", ")
+            .map(|s| valve_names.get(s).copied().ok_or_else(|| line.error(s, "unknown valve")))
+            .collect::<Result<BitSet, _>>()?;
+        result[id] = Valve{flow, neighbors};
+    }
+
+    let aa_id = *valve_names.get("AA").ok_or_else(|| truncated(input, "a valve named AA"))?;
+    Ok((aa_id, result))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+struct BitSet(u64);
+
+impl FromIterator<u32> for BitSet {
+    fn from_iter<T: IntoIterator<Item = u32>>(iter: T) -> Self {
+        let mut result = BitSet(0);
+        for v in iter {
+            result.0 |= 1 << v;
+        }
+        result
+    }
+}
+
+impl IntoIterator for BitSet {
+    type Item = u32;
+    type IntoIter = BitSetIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BitSetIterator { set: self.0, next_bit: 0 }
+    }
+}
+
+impl BitSet {
+    fn contains(&self, value: u32) -> bool {
+        self.0 & (1 << value) != 0
+    }
+}
+
+// Add a given value to the set
+impl Add<u32> for BitSet {
+    type Output = Self;
+
+    fn add(self, rhs: u32) -> Self::Output {
+        BitSet(self.0 | (1 << rhs))
+    }
+}
+
+// Remove a given value from the set
+impl Sub<u32> for BitSet {
+    type Output = Self;
+
+    fn sub(self, rhs: u32) -> Self::Output {
+        BitSet(self.0 & !(1 << rhs))
+    }
+}
+
+struct BitSetIterator {
+    set: u64,
+    next_bit: u32
+}
+
+impl Iterator for BitSetIterator {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_bit < 64 && self.set & (1 << self.next_bit) == 0 {
+            self.next_bit += 1;
+        }
+        if self.next_bit < 64 {
+            let val = self.next_bit;
+            self.next_bit += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+}
+
+//
+// The tunnels are a random tree (so every valve can be reached), with a
+// few more tunnels added.  Valve AA has no flow, like the real input.
+//
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 64);
+    let mut names = vec!["AA".to_string()];
+    while names.len() < count {
+        let name = (0..2).map(|_| rng.lowercase().to_ascii_uppercase()).collect::<String>();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut flows = vec![0; count];
+    for _ in 0..(count / 4).max(1) {
+        flows[rng.below(count - 1) + 1] = rng.range(1..=25);
+    }
+
+    let mut tunnels = vec![Vec::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for valve in 1..count {
+        connect(valve, rng.below(valve));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.below(count), rng.below(count));
+    }
+
+    let mut lines = (0..count).map(|valve| {
+        let neighbors = tunnels[valve].iter().map(|&n| names[n].as_str()).collect::<Vec<_>>();
+        let tunnels = if neighbors.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+        format!("Valve {} has flow rate={}; {tunnels} {}\n", names[valve], flows[valve], neighbors.join(", "))
+    }).collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+// A valve with flow where you start, and one that can't be reached
+#[test]
+fn test_unreachable_valves() {
+    let input = "\
+Valve AA has flow rate=5; tunnel leads to valve BB
+Valve BB has flow rate=0; tunnel leads to valve AA
+Valve CC has flow rate=7; tunnel leads to valve CC
+";
+    assert_eq!(part1(input).unwrap(), 5 * 29);
+    assert_eq!(part2(input).unwrap(), 5 * 25);
+    assert_eq!(part2b(input).unwrap(), 5 * 25);
+}
@@ -0,0 +1,623 @@
+use std::collections::HashMap;
+use fxhash::FxHashMap;
+use anyhow::bail;
+use log::debug;
+use crate::{Animation, Param, Params, Rng, Solution, Strategy};
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn part1(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part1(parse_input(input)?, params.get("rocks")?, |_, _| {}).to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part2_heights(parse_input(input)?, params.get("part2_rocks")?)?.to_string())
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "rocks", default: "2022", help: "Rocks to drop (part 1)" },
+            Param { name: "part2_rocks", default: "1000000000000", help: "Rocks to drop (part 2)" },
+        ]
+    }
+
+    fn strategies(&self) -> &'static [Strategy] {
+        &[
+            Strategy {
+                part: 2, name: "heights", slow: false,
+                help: "Find the cycle by comparing column heights",
+                solve: |input, params| Ok(part2_heights(parse_input(input)?, params.get("part2_rocks")?)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "top", slow: false,
+                help: "Find the cycle by comparing the top of the chamber",
+                solve: |input, params| Ok(part2(parse_input(input)?, params.get("part2_rocks")?)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "simulate", slow: true,
+                help: "Drop every one of the trillion rocks",
+                solve: |input, params| Ok(part2_slow(parse_input(input)?, params.get("part2_rocks")?).to_string()),
+            },
+        ]
+    }
+
+    // A frame for each push and fall of a rock.  Part 2 is the same
+    // simulation, for much longer.
+    fn visualize(&self, part: u8, input: &str, params: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+        if part != 1 {
+            bail!("Day 17 can only draw part 1");
+        }
+        part1(parse_input(input)?, params.get("rocks")?, |chamber, falling| match falling {
+            Some(_) => animation.step(|| render_chamber(chamber, falling)),
+            None => animation.last(|| render_chamber(chamber, falling)),
+        });
+        Ok(())
+    }
+
+    // `size` jets (at least 10).  Some patterns of jets never push rocks
+    // into one of the columns, so the top of the tower never repeats, and
+    // part 2 can't skip ahead.  Those are thrown away.
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        loop {
+            let jets = (0..size.max(10)).map(|_| *rng.choose(&['<', '>'])).collect::<String>();
+            let mut depth = 0;
+            part1(&jets, 2022, |chamber, falling| if falling.is_none() {
+                depth = surface(chamber).map_or(usize::MAX, |surface| surface.len());
+            });
+            if depth <= 100 {
+                return Ok(jets + "\n");
+            }
+        }
+    }
+}
+
+//
+// The input is one line of jets, each '<' or '>'.  The solvers assume
+// that has been checked, and that the trailing newline is gone.
+//
+fn parse_input(input: &str) -> Result<&str, ParseError> {
+    let line = lines(input).next().ok_or_else(|| truncated(input, "a line of jets"))?;
+    if let Some(i) = line.text.find(|ch| ch != '<' && ch != '>') {
+        let ch = line.text[i..].chars().next().unwrap();
+        return Err(line.error(&line.text[i..i+ch.len_utf8()], "expected '<' or '>'"));
+    }
+    if line.text.is_empty() {
+        return Err(line.invalid("expected at least one jet"));
+    }
+    Ok(line.text)
+}
+
+//
+// Part 1
+//
+// I think the representation for a rock should be one u16 per row.
+// The u16 is a bitmap of the positions occupied by the rock.
+// Bits 0 and 8 are the chamber walls. Should bits be high to low or
+// low to high when going left to right?  For visual convenience, I'll
+// go high order to the left. The initial bitmap for a rock is its
+// position two units from the left edge.
+//
+// The chamber where rocks fall will just be a Vec of u16.  Since
+// Vec grows most easily from the tail end, I'm going to pretend that
+// index 0 is just above the floor, and larger indices are higher up.
+// This means that first byte of a rock should be closest to the floor.
+// For ease of collision detection, the bitmaps in the chamber will
+// have the chamber walls set.
+//
+// `on_step` gets the chamber and the falling rock (and its height) after
+// each move, and the chamber without a rock at the end.
+//
+fn part1(input: &str, rocks_to_drop: usize, mut on_step: impl FnMut(&[u16], Option<(usize, &[u16])>)) -> usize {
+    let mut input = input.chars().cycle();
+    let rocks: Vec<Vec<u16>> = vec![
+        vec![0b00111100],
+        vec![0b00010000, 0b00111000, 0b00010000],
+        vec![0b00111000, 0b00001000, 0b00001000],
+        vec![0b00100000, 0b00100000, 0b00100000, 0b00100000],
+        vec![0b00110000, 0b00110000]
+    ];
+    let mut rocks = rocks.iter().cycle();
+
+    const CHAMBER_WALLS: u16 = 0b100000001;
+    let mut chamber: Vec<u16> = Vec::with_capacity(4000);
+    let mut chamber_used = 0;
+
+    for _iteration in 0..rocks_to_drop {
+        // println!("== {_iteration} ==");
+
+        // Get the next rock
+        let mut rock = rocks.next().unwrap().clone();
+
+        // Set the initial height of the rock
+        let mut height = chamber_used + 3;
+
+        // Make sure the chamber is tall enough to accomodate the
+        // current rock at its initial height
+        chamber.resize(height + rock.len(), CHAMBER_WALLS);
+        on_step(&chamber, Some((height, &rock)));
+
+        loop {
+            // Try to push rock left or right based on input
+            let movement = match input.next().unwrap() {
+                '>' => |v: u16| v >> 1,
+                '<' => |v: u16| v << 1,
+                _ => unreachable!("checked by parse_input"),
+            };
+            if rock.iter().enumerate().all(|(i,v)| chamber[height+i] & movement(*v) == 0) {
+                for v in rock.iter_mut() {
+                    *v = movement(*v);
+                }
+            }
+            on_step(&chamber, Some((height, &rock)));
+
+            // Try to push rock down
+            if height > 0 && rock.iter().enumerate().all(|(i,v)| chamber[height+i-1] & v == 0) {
+                height -= 1;
+            } else {
+                // Rock comes to rest
+                chamber_used = chamber_used.max(height + rock.len());
+                for (i, v) in rock.into_iter().enumerate() {
+                    chamber[height + i] |= v;
+                }
+                break;
+            }
+            on_step(&chamber, Some((height, &rock)));
+        }
+    }
+    on_step(&chamber[..chamber_used], None);
+
+    chamber_used
+}
+
+//
+// Clearly, we have to find a repeating pattern, and use that to skip most of
+// the repetitions.  A challenge in finding such a pattern is "uneven edges."
+// It will consume some initial part of the input before it falls into the
+// pattern.  But the most recent (top) rows of the chamber won't match
+// because they are not fully filled in yet (they're waiting for more input).
+// So how do I correlate position in the input to row position in the chamber?
+//
+// The cycle detection needs to include the offset into the input (left/right
+// moves) sequence, the offset into the rock sequence, and something about
+// the shape of the settled rocks.
+//
+// Actually, can I assume that it will repeat every (# rocks) * (# left/right)
+// iterations?  (In my case, 10,091 * 5 = 50,455)
+// NO!  A single rock dropping consumes more than one character of input.
+//
+// For my input, the cycle appears to be 1745 rocks (iterations),
+// with a height change of 2750.
+//
+// Note that the cycle is NOT the first time a rock index and jet index repeat.
+// It's not even the first time that we see the same change in iterations plus
+// change in height.  It appears to be sufficient to find the third occurrence
+// of a given (rock_index, jet_index), where the cycle is between the second and
+// third occurrences.  Or should I also look at the top of the chamber?  Or look
+// for some other quantity to repeat?
+//
+// The top of the chamber it is, but only the part a rock could reach (see
+// `surface`).  Comparing rows down to where every column has some rock
+// in it isn't enough, because a rock can slide sideways under a ledge
+// that is higher up.
+//
+fn part2(input: &str, rocks_to_drop: u64) -> anyhow::Result<usize> {
+    let input_length = input.len();
+    let mut input = input.chars().cycle();
+    let rocks: Vec<Vec<u16>> = vec![
+        vec![0b00111100],
+        vec![0b00010000, 0b00111000, 0b00010000],
+        vec![0b00111000, 0b00001000, 0b00001000],
+        vec![0b00100000, 0b00100000, 0b00100000, 0b00100000],
+        vec![0b00110000, 0b00110000]
+    ];
+    let mut rocks = rocks.iter().cycle();
+
+    const CHAMBER_WALLS: u16 = 0b100000001;
+    let mut chamber: Vec<u16> = Vec::with_capacity(4000);
+    let mut chamber_used = 0;
+    let mut jet_index = 0;
+
+    let mut heights = HashMap::<(u64,usize,Vec<u16>), (u64,usize)>::new();
+    let mut cycle_found = false;
+    let mut skipped_height = 0;
+    let mut too_deep = 0;
+
+    let mut iteration: u64 = 0;
+    while iteration < rocks_to_drop {
+        // Get the next rock
+        let mut rock = rocks.next().unwrap().clone();
+
+        // Set the initial height of the rock
+        let mut height = chamber_used + 3;
+
+        // Make sure the chamber is tall enough to accomodate the
+        // current rock at its initial height
+        chamber.resize(height + rock.len(), CHAMBER_WALLS);
+
+        // Once the cycle has been skipped, there's less than one cycle to go
+        let live_edge = if cycle_found { None } else { surface(&chamber) };
+        if !cycle_found && live_edge.is_none() {
+            too_deep += 1;
+            if too_deep > DEEP_SURFACE_ROCKS {
+                bail!(NO_CYCLE);
+            }
+        }
+        if let Some(live_edge) = live_edge {
+            too_deep = 0;
+            let rock_index = iteration % 5;     // We have 5 rocks
+            if let Some((i,h)) = heights.get(&(rock_index, jet_index, live_edge.clone())) {
+                // We have detected a cycle of (iteration-i) rocks, with a height
+                // of (chamber_used-h).
+                let cycle_length = iteration - i;
+                let num_cycles = (rocks_to_drop - iteration - 1) / cycle_length;
+                skipped_height = (num_cycles as usize) * (chamber_used - h);
+                iteration += num_cycles * cycle_length;
+                cycle_found = true;
+            } else {
+                heights.insert((rock_index, jet_index, live_edge), (iteration,chamber_used));
+            }
+        }
+
+        loop {
+            // Try to push rock left or right based on input
+            let movement = match input.next().unwrap() {
+                '>' => |v: u16| v >> 1,
+                '<' => |v: u16| v << 1,
+                _ => unreachable!("checked by parse_input"),
+            };
+            jet_index = (jet_index + 1) % input_length;
+
+            if rock.iter().enumerate().all(|(i,v)| chamber[height+i] & movement(*v) == 0) {
+                for v in rock.iter_mut() {
+                    *v = movement(*v);
+                }
+            }
+
+            // Try to push rock down
+            if height > 0 && rock.iter().enumerate().all(|(i,v)| chamber[height+i-1] & v == 0) {
+                height -= 1;
+            } else {
+                // Rock comes to rest
+                chamber_used = chamber_used.max(height + rock.len());
+                for (i, v) in rock.into_iter().enumerate() {
+                    chamber[height + i] |= v;
+                }
+                break;
+            }
+        }
+        iteration += 1;
+    }
+
+    Ok(chamber_used + skipped_height)
+}
+
+//
+// Return the empty spaces that a falling rock could get to, from the top
+// of the chamber down, one bitmap per row.  Rocks only move down, left and
+// right, so a row's spaces are the ones under a reachable space in the row
+// above, and whatever is beside those.  It stops at the first row with no
+// reachable spaces (or at the floor).
+//
+// Where the rocks land only depends on these spaces, so if the surface
+// (and the next rock and jet) repeat, then so does everything after.
+//
+// If the surface is more than `SURFACE_LIMIT` rows deep, the jets are
+// probably keeping the rocks out of a column, so it may never repeat.
+// Rather than comparing ever longer surfaces, return None.
+//
+const SURFACE_LIMIT: usize = 1000;
+
+// When the surface stays too deep for this many rocks in a row, the top
+// of the tower is never going to repeat, and part 2 gives up.
+const DEEP_SURFACE_ROCKS: usize = 10_000;
+const NO_CYCLE: &str = "the top of the tower doesn't repeat (the jets keep the rocks out of a column), so there's no way to skip ahead";
+
+fn surface(chamber: &[u16]) -> Option<Vec<u16>> {
+    let mut result = Vec::new();
+    let mut reachable = 0b011111110;
+    for row in chamber.iter().rev() {
+        let empty = !row & 0b011111110;
+        reachable &= empty;
+        loop {
+            let spread = (reachable | reachable << 1 | reachable >> 1) & empty;
+            if spread == reachable {
+                break;
+            }
+            reachable = spread;
+        }
+        if reachable == 0 {
+            break;
+        }
+        if result.len() == SURFACE_LIMIT {
+            return None;
+        }
+        result.push(reachable);
+    }
+    Some(result)
+}
+
+fn part2_heights(input: &str, rocks_to_drop: usize) -> anyhow::Result<usize> {
+    #[derive(Debug, Hash, PartialEq, Eq)]
+    struct StateKey {
+        rock_index: usize,
+        jet_index: usize,
+        // heights are the per-column distance from the highest rock in all
+        // columns down to the lowest space a rock could reach in that column.
+        // (The highest rock in each column isn't enough: a rock can slide
+        // under a ledge.)
+        heights: [usize; 7]
+    }
+    #[derive(Debug, PartialEq, Eq)]
+    struct StateVal {
+        iteration: usize,
+        chamber_used: usize
+    }
+    let mut jets = input.chars().enumerate().cycle();
+    let rocks: Vec<Vec<u16>> = vec![
+        vec![0b00111100],
+        vec![0b00010000, 0b00111000, 0b00010000],
+        vec![0b00111000, 0b00001000, 0b00001000],
+        vec![0b00100000, 0b00100000, 0b00100000, 0b00100000],
+        vec![0b00110000, 0b00110000]
+    ];
+    let mut rocks = rocks.iter().cloned().enumerate().cycle();
+
+    const CHAMBER_WALLS: u16 = 0b100000001;
+    let mut chamber: Vec<u16> = Vec::with_capacity(4000);
+    let mut chamber_used = 0;
+    let mut cycle_used = 0;
+
+    let mut states = FxHashMap::<StateKey, StateVal>::default();
+    let mut cycle_found = false;
+    let mut too_deep = 0;
+
+    let mut iteration: usize = 0;
+    while iteration < rocks_to_drop {
+        // Get the next rock
+        let (rock_index, mut rock) = rocks.next().unwrap();
+
+        // Set the initial height of the rock
+        let mut height = chamber_used + 3;
+
+        // Make sure the chamber is tall enough to accomodate the
+        // current rock at its initial height
+        chamber.resize(height + rock.len(), CHAMBER_WALLS);
+
+        let mut jet_index;
+        let mut jet;
+        loop {
+            // Try to push rock left or right based on the next jet (input)
+            (jet_index, jet) = jets.next().unwrap();
+            let movement = match jet {
+                '>' => |v: u16| v >> 1,
+                '<' => |v: u16| v << 1,
+                _ => unreachable!("checked by parse_input"),
+            };
+
+            if rock.iter().enumerate().all(|(i,v)| chamber[height+i] & movement(*v) == 0) {
+                for v in rock.iter_mut() {
+                    *v = movement(*v);
+                }
+            }
+
+            // Try to push rock down
+            if height > 0 && rock.iter().enumerate().all(|(i,v)| chamber[height+i-1] & v == 0) {
+                height -= 1;
+            } else {
+                // Rock comes to rest
+                chamber_used = chamber_used.max(height + rock.len());
+                for (i, v) in rock.into_iter().enumerate() {
+                    chamber[height + i] |= v;
+                }
+                break;
+            }
+        }
+
+        // Look for a repeating cycle
+        let surface = if cycle_found { None } else { surface(&chamber[..chamber_used]) };
+        if !cycle_found && surface.is_none() {
+            too_deep += 1;
+            if too_deep > DEEP_SURFACE_ROCKS {
+                bail!(NO_CYCLE);
+            }
+        }
+        if let Some(surface) = surface {
+            too_deep = 0;
+            // The depth of the lowest space a rock could reach, per column
+            let mut heights = [0; 7];
+            for (depth, reachable) in surface.into_iter().enumerate() {
+                for (h, mask) in heights.iter_mut().zip([128,64,32,16,8,4,2]) {
+                    if reachable & mask != 0 {
+                        *h = depth + 1;
+                    }
+                }
+            }
+            let state_key = StateKey { rock_index, jet_index, heights };
+            // println!("[{iteration}] state_key = {:?}", state_key);
+            if let Some(old_val) = states.get(&state_key) {
+                let cycle_iterations = iteration - old_val.iteration;
+                let cycle_height = chamber_used - old_val.chamber_used;
+                let num_cycles = (rocks_to_drop - iteration - 1) / cycle_iterations;
+                cycle_used = num_cycles * cycle_height;
+                cycle_found = true;
+                iteration += num_cycles * cycle_iterations;
+            } else {
+                states.insert(state_key,StateVal { iteration, chamber_used });
+            }
+        }
+
+        iteration += 1;
+    }
+
+    Ok(chamber_used + cycle_used)
+}
+
+
+//
+// Part 2, by brute force.  Simulate every rock, periodically discarding
+// the bottom of the chamber to keep memory use bounded.  It takes far
+// too long to be practical.
+//
+fn part2_slow(input: &str, rocks_to_drop: u64) -> usize {
+    let mut input = input.chars().cycle();
+    let rocks: Vec<Vec<u16>> = vec![
+        vec![0b00111100],
+        vec![0b00010000, 0b00111000, 0b00010000],
+        vec![0b00111000, 0b00001000, 0b00001000],
+        vec![0b00100000, 0b00100000, 0b00100000, 0b00100000],
+        vec![0b00110000, 0b00110000]
+    ];
+    let mut rocks = rocks.iter().cycle();
+
+    const CHAMBER_WALLS: u16 = 0b100000001;
+    let mut chamber: Vec<u16> = Vec::with_capacity(4000);
+    let mut chamber_used = 0;
+    let mut shifted = 0;
+
+    for iteration in 0..rocks_to_drop {
+        if iteration.is_multiple_of(1_000_000) {
+            debug!("{} million rocks", iteration / 1_000_000);
+        }
+
+        // Get the next rock
+        let mut rock = rocks.next().unwrap().clone();
+
+        // Set the initial height of the rock
+        let mut height = chamber_used + 3;
+
+        // Make sure the chamber is tall enough to accomodate the
+        // current rock at its initial height
+        chamber.resize(height + rock.len(), CHAMBER_WALLS);
+
+        loop {
+            // Try to push rock left or right based on input
+            let movement = match input.next().unwrap() {
+                '>' => |v: u16| v >> 1,
+                '<' => |v: u16| v << 1,
+                _ => unreachable!("checked by parse_input"),
+            };
+            if rock.iter().enumerate().all(|(i,v)| chamber[height+i] & movement(*v) == 0) {
+                for v in rock.iter_mut() {
+                    *v = movement(*v);
+                }
+            }
+
+            // Try to push rock down
+            if height > 0 && rock.iter().enumerate().all(|(i,v)| chamber[height+i-1] & v == 0) {
+                height -= 1;
+            } else {
+                // Rock comes to rest
+                chamber_used = chamber_used.max(height + rock.len());
+                for (i, v) in rock.into_iter().enumerate() {
+                    chamber[height + i] |= v;
+                }
+                break;
+            }
+        }
+
+        // Forget the rows that no rock can reach any more.  (Not just all
+        // but the top 100: a rock can slide sideways under a ledge, and
+        // get further down than that.)  When the surface is deeper than
+        // `SURFACE_LIMIT`, keep everything.
+        if chamber_used > 2 * SURFACE_LIMIT {
+            if let Some(surface) = surface(&chamber[..chamber_used]) {
+                let unreachable = chamber_used - surface.len();
+                chamber.drain(0..unreachable);
+                shifted += unreachable;
+                chamber_used -= unreachable;
+            }
+        }
+    }
+
+    shifted + chamber_used
+}
+
+//
+// Draw the top of the chamber (with the falling rock as '@'), like the
+// pictures in the puzzle.
+//
+fn render_chamber(chamber: &[u16], falling: Option<(usize, &[u16])>) -> String {
+    const ROWS: usize = 40;
+    let mut result = String::new();
+    for (h, v) in chamber.iter().enumerate().rev().take(ROWS) {
+        result.push('|');
+        for mask in [128, 64, 32, 16, 8, 4, 2] {
+            let in_rock = match falling {
+                Some((height, rock)) if h >= height && h < height + rock.len() => rock[h - height] & mask != 0,
+                _ => false,
+            };
+            result.push(if in_rock { '@' } else if v & mask != 0 { '#' } else { '.' });
+        }
+        result.push_str("|\n");
+    }
+    if chamber.len() <= ROWS {
+        result.push_str("+-------+\n");
+    }
+    result
+}
+
+// The fixtures skip slow strategies
+#[test]
+#[ignore]
+fn test_part2_slow() {
+    let input = include_str!("../../../fixtures/day17/example.txt");
+    assert_eq!(part2_slow(parse_input(input).unwrap(), 1_000_000_000_000), 1514285714288);
+}
+
+//
+// Bugs in part 2's strategies.  Each answer here is the same as part 1
+// (which never skips or forgets anything) dropping that many rocks.
+//
+
+// After skipping ahead by whole cycles, the rock at hand is still
+// dropped, so the skip has to stop one rock short.  It didn't: with
+// 2022 rocks, "heights" got 3070, and with 2023 rocks, "top" got 3072.
+#[test]
+fn test_cycle_skip_leaves_last_rock() {
+    let input = parse_input(include_str!("../../../fixtures/day17/example.txt")).unwrap();
+    assert_eq!(part1(input, 2022, |_, _| {}), 3068);
+    assert_eq!(part2_heights(input, 2022).unwrap(), 3068);
+    assert_eq!(part2(input, 2022).unwrap(), 3068);
+    assert_eq!(part1(input, 2023, |_, _| {}), 3070);
+    assert_eq!(part2_heights(input, 2023).unwrap(), 3070);
+    assert_eq!(part2(input, 2023).unwrap(), 3070);
+}
+
+// A rock can slide sideways under a ledge, so a cycle has to be found by
+// the spaces a rock could reach.  The highest rock in each column wasn't
+// enough ("heights" got 5036), and neither were the rows down to where
+// every column has some rock ("top" got 1656).
+#[test]
+fn test_cycle_key_sees_under_ledges() {
+    let jets = "><><><><<>><<><><>>><<>>>>";
+    assert_eq!(part1(jets, 3374, |_, _| {}), 4846);
+    assert_eq!(part2_heights(jets, 3374).unwrap(), 4846);
+
+    let jets = ">>>><<<<><<<><><<<>><>><>>>><>";
+    assert_eq!(part1(jets, 1068, |_, _| {}), 1608);
+    assert_eq!(part2(jets, 1068).unwrap(), 1608);
+}
+
+// For the same reason, "simulate" can't forget all but the top rows of
+// the chamber.  Keeping 100 rows, it got 1044 here.
+#[test]
+fn test_simulate_keeps_reachable_rows() {
+    let jets = "<><><><><>>>";
+    assert_eq!(part1(jets, 563, |_, _| {}), 1048);
+    assert_eq!(part2_slow(jets, 563), 1048);
+    assert_eq!(part2_slow(jets, 5000), part1(jets, 5000, |_, _| {}));
+}
+
+// Jets that only push right keep the rocks out of the left column, so the
+// top of the tower never repeats.
+#[test]
+fn test_no_cycle() {
+    assert_eq!(part2_heights(">", 1_000_000_000_000).unwrap_err().to_string(), NO_CYCLE);
+    assert_eq!(part2(">", 1_000_000_000_000).unwrap_err().to_string(), NO_CYCLE);
+}
@@ -0,0 +1,340 @@
+use std::{cell::RefCell, ops::RangeInclusive};
+use pathfinding::prelude::{dfs, dijkstra};
+use fxhash::{FxHashSet as HashSet, FxHashMap as HashMap};
+use crate::{BoxSet, Params, Rng, Solution, Strategy};
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+
+    fn strategies(&self) -> &'static [Strategy] {
+        &[
+            Strategy {
+                part: 1, name: "points", slow: false,
+                help: "Check each cube's six neighbors",
+                solve: |input, _| Ok(part1(input)?.to_string()),
+            },
+            Strategy {
+                part: 1, name: "boxes", slow: false,
+                help: "Compare the droplet with itself shifted one cube each way, as sets of boxes",
+                solve: |input, _| Ok(part1_boxes(input)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "points", slow: false,
+                help: "Search from each neighbor for a way out of the bounding box",
+                solve: |input, _| Ok(part2(input)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "boxes", slow: false,
+                help: "Grow the outside air as a set of boxes, then fill in the rest",
+                solve: |input, _| Ok(part2_boxes(input)?.to_string()),
+            },
+        ]
+    }
+
+    // `size` cubes, filling about half of a box (so there are air pockets)
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let size = size.max(1);
+        let side = ((size * 2) as f64).cbrt().ceil() as i64;
+        let mut cubes = HashSet::default();
+        let mut result = String::new();
+        while cubes.len() < size {
+            let cube = (rng.range(1..=side), rng.range(1..=side), rng.range(1..=side));
+            if cubes.insert(cube) {
+                result.push_str(&format!("{},{},{}\n", cube.0, cube.1, cube.2));
+            }
+        }
+        Ok(result)
+    }
+}
+
+fn part1(input: &str) -> Result<usize, ParseError> {
+    let cubes = parse_input(input)?;
+
+    Ok(cubes.iter().map(|cube| {
+        cube_neighbors(cube)
+            .iter()
+            .filter(|cube| !cubes.contains(cube))
+            .count()
+    }).sum())
+}
+
+//
+// Part 2
+//
+// Similar to part 1, except don't count faces where the neighbor is
+// interior to the droplet (i.e. there is no path to outside the drop's
+// bounding box).
+//
+fn part2(input: &str) -> Result<usize, ParseError> {
+    let lava = Lava::new(input)?;
+
+    Ok(lava.iter().map(|cube| {
+        cube_neighbors(cube)
+            .iter()
+            .filter(|neighbor| lava.is_exterior_dfs(neighbor))
+            .count()
+    }).sum())
+}
+
+//
+// The same answers, by region algebra on the droplet as a `BoxSet`.
+//
+// A cube's face is exposed if its neighbor in that direction is not
+// lava.  Shifting the whole droplet one cube the other way lines each
+// cube up with that neighbor, so the exposed faces in one direction are
+// the cubes of the droplet that are not in the shifted droplet.
+//
+// For part 2, the outside air starts as one corner of a box around the
+// droplet, with room to spare on every side.  It grows one cube in every
+// direction at a time (staying out of the lava) until it stops growing.
+// Whatever air is left is trapped inside, and filling it in leaves only
+// the exterior surface.
+//
+type Droplet = BoxSet<i32, 3>;
+
+fn part1_boxes(input: &str) -> Result<i32, ParseError> {
+    Ok(surface_area(&droplet(&parse_input(input)?)))
+}
+
+fn part2_boxes(input: &str) -> Result<i32, ParseError> {
+    let cubes = parse_input(input)?;
+    let lava = droplet(&cubes);
+    let (x, y, z) = get_bounds(&cubes);
+    let outer = |r: RangeInclusive<i8>| *r.start() as i32 - 1 ..= *r.end() as i32 + 1;
+    let bounds = [outer(x), outer(y), outer(z)];
+
+    let mut air = Droplet::from_box(bounds.clone());
+    air = air.difference(&lava);
+    let mut outside = Droplet::from_box(bounds.map(|r| *r.start()));
+    loop {
+        let mut grown = outside.clone();
+        for shifted in neighbors(&outside) {
+            grown = grown.union(&shifted);
+        }
+        grown = grown.intersection(&air);
+        if grown == outside {
+            break;
+        }
+        outside = grown;
+    }
+
+    let trapped = air.difference(&outside);
+    Ok(surface_area(&lava.union(&trapped)))
+}
+
+fn droplet(cubes: &HashSet<Point>) -> Droplet {
+    cubes.iter().map(|&(x, y, z)| [x as i32, y as i32, z as i32]).collect()
+}
+
+fn surface_area(droplet: &Droplet) -> i32 {
+    neighbors(droplet).iter().map(|shifted| droplet.difference(shifted).volume()).sum()
+}
+
+// The set moved one cube in each of the six directions
+fn neighbors(set: &Droplet) -> Vec<Droplet> {
+    let boxes = set.boxes();
+    let mut result = Vec::new();
+    for axis in 0..3 {
+        for delta in [-1, 1] {
+            result.push(boxes.iter().map(|bounds| {
+                let mut bounds = bounds.clone();
+                bounds[axis] = bounds[axis].start + delta .. bounds[axis].end + delta;
+                bounds
+            }).collect());
+        }
+    }
+    result
+}
+
+type Point = (i8,i8,i8);
+type BoundingBox = (RangeInclusive<i8>, RangeInclusive<i8>, RangeInclusive<i8>);
+
+struct Lava {
+    cubes: HashSet<Point>,
+    bounds: BoundingBox,
+    exterior_cache: RefCell<HashMap<Point,bool>>,
+}
+
+impl Lava {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let cubes = parse_input(input)?;
+        let bounds = get_bounds(&cubes);
+        let exterior_cache = RefCell::new(HashMap::default());
+        Ok(Self { cubes, bounds, exterior_cache })
+    }
+
+    #[allow(dead_code)]
+    fn contains(&self, point: &Point) -> bool {
+        self.cubes.contains(point)
+    }
+
+    fn iter(&self) -> impl Iterator<Item=&Point> {
+        self.cubes.iter()
+    }
+
+    #[allow(dead_code)]
+    fn is_exterior_cached(&self, point: &Point) -> bool {
+        if let Some(result) = self.exterior_cache.borrow().get(point) {
+            return *result;
+        }
+
+        let result = self.is_exterior(point);
+        self.exterior_cache.borrow_mut().insert(*point, result);
+        result
+    }
+
+    //
+    // A cube is exterior if it has a path to a point outside the bounding box
+    //
+    #[allow(dead_code)]
+    fn is_exterior(&self, point: &Point) -> bool {
+        if self.cubes.contains(point) {
+            return false;
+        }
+
+        let successors = |cube: &Point| -> Vec<(Point,u8)> {
+            cube_neighbors(cube).into_iter()
+                .filter(|p| !self.cubes.contains(p))
+                .map(|p| (p,1))
+                .collect()
+        };
+        let success = |cube: &Point| -> bool {
+            !self.bounds.0.contains(&cube.0) ||
+            !self.bounds.1.contains(&cube.1) ||
+            !self.bounds.2.contains(&cube.2)
+        };
+        dijkstra(point, successors, success).is_some()
+    }
+
+    fn out_of_bounds(&self, point: &Point) -> bool {
+        !self.bounds.0.contains(&point.0) ||
+        !self.bounds.1.contains(&point.1) ||
+        !self.bounds.2.contains(&point.2)
+    }
+
+    //
+    // Determine whether the given point is connected to any point
+    // point outside the bounding box.  Makes use of, and maintains,
+    // the exterior_cache.
+    //
+    // This uses a depth-first search.  Every point visited in the search,
+    // or waiting to be visited, and therefore connected to the original
+    // point, will be added to exterior_cache once the answer is known.
+    //
+    fn is_exterior_dfs(&self, point: &Point) -> bool {
+        if let Some(result) = self.exterior_cache.borrow().get(point) {
+            return *result;
+        }
+        if self.cubes.contains(point) {
+            return false;
+        }
+
+        let mut visited = HashSet::default();
+
+        let successors = |cube: &Point| {
+            let neighbors: Vec<_> = cube_neighbors(cube).into_iter()
+                .filter(|p| !self.cubes.contains(p) && !visited.contains(p))
+                .collect();
+            visited.extend(neighbors.iter().cloned());
+            neighbors
+        };
+        let success = |cube: &Point| -> bool {
+            if self.out_of_bounds(cube) {
+                // We've reached an exterior point.  Add it to the cache.
+                // When we fall through, we will return `true` from this
+                // closure.
+                self.exterior_cache.borrow_mut().insert(*cube, true);
+            }
+            // In this case, "success" means that we have determined
+            // whether the original point is exterior or interior.
+            // If not, we have to keep searching.
+            self.exterior_cache.borrow().contains_key(cube)
+        };
+
+        let mut result = false;
+        if let Some(path) = dfs(*point, successors, success) {
+            let last = path.last().unwrap();
+            let cache = self.exterior_cache.borrow_mut();
+            result = cache.get(last).cloned().unwrap();
+        }
+        let mut cache = self.exterior_cache.borrow_mut();
+        cache.extend(visited.into_iter().map(|p| (p, result)));
+        cache.insert(*point, result);
+        result
+    }
+}
+
+fn get_bounds(lava: &HashSet<Point>) -> BoundingBox {
+    // Get one of the cubes from the lava to initialize our ranges.
+    let cube = lava.iter().next().unwrap();
+    let mut xmin = cube.0;
+    let mut xmax = cube.0;
+    let mut ymin = cube.1;
+    let mut ymax = cube.1;
+    let mut zmin = cube.2;
+    let mut zmax = cube.2;
+
+    // Expand the ranges to include all of the cubes
+    for cube in lava {
+        xmin = xmin.min(cube.0);
+        xmax = xmax.max(cube.0);
+        ymin = ymin.min(cube.1);
+        ymax = ymax.max(cube.1);
+        zmin = zmin.min(cube.2);
+        zmax = zmax.max(cube.2);
+    }
+
+    (xmin..=xmax, ymin..=ymax, zmin..=zmax)
+}
+
+fn cube_neighbors(&(x,y,z): &Point) -> Vec<Point> {
+    vec![
+        (x+1,y,z),
+        (x-1,y,z),
+        (x,y+1,z),
+        (x,y-1,z),
+        (x,y,z+1),
+        (x,y,z-1)
+    ]
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Point>, ParseError> {
+    let mut cubes = HashSet::default();
+    for line in lines(input) {
+        let [x, y, z] = line.fields(",")?;
+        let point = (line.parse::<i8>(x)?, line.parse::<i8>(y)?, line.parse::<i8>(z)?);
+        cubes.insert(point);
+    }
+    if cubes.is_empty() {
+        return Err(truncated(input, "at least one cube"));
+    }
+    Ok(cubes)
+}
+
+#[test]
+fn test_boxes() {
+    let input = include_str!("../../../fixtures/day18/example.txt");
+    assert_eq!(part1_boxes(input).unwrap(), 64);
+    assert_eq!(part2_boxes(input).unwrap(), 58);
+
+    // One cube of trapped air, in the middle of a 3x3x3 cube
+    let hollow = (1..=3).flat_map(|x| (1..=3).flat_map(move |y| (1..=3).map(move |z| (x, y, z))))
+        .filter(|&cube| cube != (2, 2, 2))
+        .map(|(x, y, z)| format!("{x},{y},{z}\n"))
+        .collect::<String>();
+    assert_eq!(part1_boxes(&hollow).unwrap(), 54 + 6);
+    assert_eq!(part2_boxes(&hollow).unwrap(), 54);
+}
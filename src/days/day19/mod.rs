@@ -1,30 +1,55 @@
 use pathfinding::prelude::dfs_reach;
 use rayon::prelude::*;
+use std::str::FromStr;
+use crate::{Param, Params, Rng, Solution};
+use crate::search::SearchStats;
+use crate::parse::{lines, Line, ParseError};
 
-fn main() {
-    let path = std::env::args().nth(1)
-        .unwrap_or_else(|| "src/bin/day19/input.txt".into());
-    let input = std::fs::read_to_string(path).unwrap();
-    let blueprints: Vec<_> = input.lines().map(Blueprint::new).collect();
-    
-    let result1 = part1(&blueprints);
-    println!("Part 1: {}", result1);
-    assert_eq!(result1, 1199);
-    
-    let result2 = part2(&blueprints);
-    println!("Part 2: {}", result2);
-    assert_eq!(result2, 3510);
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn part1(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        let blueprints = parse_input(input)?;
+        Ok(part1(&blueprints, params.get("minutes")?).to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        let blueprints = parse_input(input)?;
+        Ok(part2(&blueprints, params.get("part2_minutes")?, params.get("blueprints")?).to_string())
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "minutes", default: "24", help: "Minutes to collect geodes (part 1)" },
+            Param { name: "part2_minutes", default: "32", help: "Minutes to collect geodes (part 2)" },
+            Param { name: "blueprints", default: "3", help: "How many blueprints the elephants didn't eat (part 2)" },
+        ]
+    }
+
+    // `size` blueprints
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok((1..=size.max(1)).map(|id| {
+            format!(
+                "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                rng.range(2..=4), rng.range(2..=4), rng.range(2..=4), rng.range(5..=20), rng.range(2..=4), rng.range(5..=20)
+            )
+        }).collect())
+    }
 }
 
-fn part1(blueprints: &[Blueprint]) -> u16 {
+fn part1(blueprints: &[Blueprint], minutes: u16) -> u32 {
     blueprints.par_iter().map(|blueprint|
-        blueprint.id * collect_geodes(blueprint, 24)
+        blueprint.id as u32 * collect_geodes(blueprint, minutes) as u32
     ).sum()
 }
 
-fn part2(blueprints: &[Blueprint]) -> u32 {
-    blueprints.par_iter().take(3).map(|blueprint|
-        collect_geodes(blueprint, 32) as u32
+fn part2(blueprints: &[Blueprint], minutes: u16, count: usize) -> u64 {
+    blueprints.par_iter().take(count).map(|blueprint|
+        collect_geodes(blueprint, minutes) as u64
     ).product()
 }
 
@@ -65,6 +90,7 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
 
     // Best solution found so far
     let mut max_geodes = 0;
+    let stats = SearchStats::new(module_path!(), format!("blueprint {}, {minutes} minutes", blueprint.id));
 
     let collect_resources = |state: &mut State| {
         if state.minutes > 0 {
@@ -124,6 +150,7 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
     };
     let successors = |state: &State| {
         // dbg!(state);
+        stats.pop();
         max_geodes = max_geodes.max(state.geodes);
 
         let mut result = vec![];
@@ -134,6 +161,7 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
 
         // If this state couldn't possibly beat our best solution, ignore it.
         if max_geodes_for_state(state) <= max_geodes {
+            stats.prune("bound");
             return result;
         }
 
@@ -156,6 +184,8 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
                 next_state.obsidian_robots += 1;
                 result.push(next_state);
             }
+        } else {
+            stats.prune("robot cap");
         }
 
         // Can/should we make a clay robot?
@@ -165,6 +195,8 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
                 next_state.clay_robots += 1;
                 result.push(next_state);
             }
+        } else {
+            stats.prune("robot cap");
         }
 
         // Can/should we make an ore robot?
@@ -174,6 +206,8 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
                 next_state.ore_robots += 1;
                 result.push(next_state);
             }
+        } else {
+            stats.prune("robot cap");
         }
 
         // If we weren't able to make any robots, it's because there isn't
@@ -187,13 +221,18 @@ fn collect_geodes(blueprint: &Blueprint, minutes: u16) -> u16 {
             result.push(next_state);
         }
 
+        for state in &result {
+            stats.push_state(state);
+        }
         result
     };
 
-    dfs_reach(start, successors)
+    let geodes = dfs_reach(start, successors)
         .filter(|state| state.minutes == 0)
         .map(|state| state.geodes)
-        .max().unwrap()
+        .max().unwrap();
+    stats.finish();
+    geodes
 }
 
 #[derive(Debug)]
@@ -207,18 +246,38 @@ struct Blueprint {
     geode_robot_obsidian_cost: u16,
 }
 
-impl Blueprint {
-    fn new(line: &str) -> Self {
-        let mut numbers = line.split(&[' ', ':'])
-            .filter_map(|word| word.parse::<u16>().ok());
-        let id = numbers.next().unwrap();
-        let ore_robot_ore_cost = numbers.next().unwrap();
-        let clay_robot_ore_cost = numbers.next().unwrap();
-        let obsidian_robot_ore_cost = numbers.next().unwrap();
-        let obsidian_robot_clay_cost = numbers.next().unwrap();
-        let geode_robot_ore_cost = numbers.next().unwrap();
-        let geode_robot_obsidian_cost = numbers.next().unwrap();
-        Self {
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    //
+    // Parse a line like (but all on one line):
+    //  Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore.
+    //  Each obsidian robot costs 3 ore and 14 clay. Each geode robot
+    //  costs 2 ore and 7 obsidian.
+    //
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(1, text);
+        let mut rest = line.strip_prefix("Blueprint ")?;
+        let mut numbers = [0; 7];
+        let separators = [
+            ": Each ore robot costs ",
+            " ore. Each clay robot costs ",
+            " ore. Each obsidian robot costs ",
+            " ore and ",
+            " clay. Each geode robot costs ",
+            " ore and ",
+            " obsidian.",
+        ];
+        for (number, separator) in numbers.iter_mut().zip(separators) {
+            let (piece, remainder) = line.split_piece(rest, separator)?;
+            *number = line.parse::<u16>(piece)?;
+            rest = remainder;
+        }
+        if !rest.is_empty() {
+            return Err(line.error(rest, "unexpected text after blueprint"));
+        }
+
+        let [
             id,
             ore_robot_ore_cost,
             clay_robot_ore_cost,
@@ -226,32 +285,25 @@ impl Blueprint {
             obsidian_robot_clay_cost,
             geode_robot_ore_cost,
             geode_robot_obsidian_cost,
-        }
+        ] = numbers;
+        Ok(Self {
+            id,
+            ore_robot_ore_cost,
+            clay_robot_ore_cost,
+            obsidian_robot_ore_cost,
+            obsidian_robot_clay_cost,
+            geode_robot_ore_cost,
+            geode_robot_obsidian_cost,
+        })
     }
 }
 
-#[test]
-fn test_collect_geodes() {
-    let blueprint = Blueprint::new("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.");
-    assert_eq!(collect_geodes(&blueprint, 24), 9);
-}
-
-#[test]
-fn test_part1() {
-    let input = "\
-    Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.\n\
-    Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.\n\
-    ";
-    let blueprints: Vec<_> = input.lines().map(Blueprint::new).collect();
-    assert_eq!(part1(&blueprints), 33);
+fn parse_input(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    lines(input).map(|line| line.parse_nested(line.text)).collect()
 }
 
 #[test]
-fn test_part2() {
-    let input = "\
-    Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.\n\
-    Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.\n\
-    ";
-    let blueprints: Vec<_> = input.lines().map(Blueprint::new).collect();
-    assert_eq!(part2(&blueprints), 56 * 62);
+fn test_collect_geodes() {
+    let blueprint: Blueprint = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.".parse().unwrap();
+    assert_eq!(collect_geodes(&blueprint, 24), 9);
 }
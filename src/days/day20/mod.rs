@@ -1,18 +1,38 @@
 use std::collections::VecDeque;
+use crate::{Param, Params, Rng, Solution};
+use crate::parse::{lines, truncated, ParseError};
 
-fn main() {
-    let path = std::env::args().nth(1)
-        .unwrap_or_else(|| "src/bin/day20/input.txt".into());
-    let input = std::fs::read_to_string(path).unwrap();
-    let numbers = parse_numbers(&input);
+pub struct Day20;
 
-    let result1 = part1(&numbers);
-    println!("Part 1: {}", result1);
-    assert_eq!(result1, 1591);
+impl Solution for Day20 {
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(&parse_numbers(input)?).to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part2(&parse_numbers(input)?, params.get("key")?, params.get("rounds")?).to_string())
+    }
+
+    fn params(&self) -> &'static [Param] {
+        &[
+            Param { name: "key", default: "811589153", help: "Decryption key (part 2)" },
+            Param { name: "rounds", default: "10", help: "Times to mix the numbers (part 2)" },
+        ]
+    }
 
-    let result2 = part2(&numbers);
-    println!("Part 2: {}", result2);
-    // assert_eq!(result2, 1591);
+    // `size` numbers, one of which is 0
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let mut numbers = (1..size.max(2)).map(|_| {
+            let n = rng.range(1..=10000);
+            if rng.chance(0.5) { -n } else { n }
+        }).collect::<Vec<_>>();
+        numbers.insert(rng.below(numbers.len() + 1), 0);
+        Ok(numbers.into_iter().map(|n| format!("{n}\n")).collect())
+    }
 }
 
 fn part1(numbers: &[i32]) -> i32 {
@@ -39,11 +59,11 @@ fn part1(numbers: &[i32]) -> i32 {
     mixed[(zero_pos + 3000) % numbers.len()].1
 }
 
-fn part2(numbers: &[i32]) -> i64 {
+fn part2(numbers: &[i32], key: i64, rounds: u32) -> i64 {
     let mut mixed:VecDeque<(usize,i64)> = numbers.iter().copied()
-        .map(|v| v as i64 * 811589153)
+        .map(|v| v as i64 * key)
         .enumerate().collect();
-    for _ in 0..10 {
+    for _ in 0..rounds {
         for index in 0..mixed.len() {
             let pos = mixed.iter().position(|&(i,_)| i == index).unwrap();
             let (i,number) = mixed.remove(pos).unwrap();
@@ -67,22 +87,12 @@ fn part2(numbers: &[i32]) -> i64 {
     mixed[(zero_pos + 3000) % numbers.len()].1
 }
 
-fn parse_numbers(s: &str) -> Vec<i32> {
-    s.lines().map(|s| s.parse::<i32>().unwrap()).collect::<Vec<_>>()
-}
-
-#[test]
-fn test_part1() {
-    let input = "1\n2\n-3\n3\n-2\n0\n4\n";
-    let numbers = parse_numbers(input);
-    // println!("{:?}", numbers);
-    assert_eq!(part1(&numbers), 3);
-}
-
-#[test]
-fn test_part2() {
-    let input = "1\n2\n-3\n3\n-2\n0\n4\n";
-    let numbers = parse_numbers(input);
-    // println!("{:?}", numbers);
-    assert_eq!(part2(&numbers), 1623178306);
+// The answer is measured from the 0, so there has to be one
+// (and at least one other number to mix around it).
+fn parse_numbers(s: &str) -> Result<Vec<i32>, ParseError> {
+    let numbers = lines(s).map(|line| line.parse::<i32>(line.text)).collect::<Result<Vec<_>, _>>()?;
+    if numbers.len() < 2 || !numbers.contains(&0) {
+        return Err(truncated(s, "a 0 and at least one other number"));
+    }
+    Ok(numbers)
 }
@@ -0,0 +1,410 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::{Add, Sub, Mul, Div};
+use crate::{Params, Rng, Solution, Strategy};
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2_linear(input)?.to_string())
+    }
+
+    fn strategies(&self) -> &'static [Strategy] {
+        &[
+            Strategy {
+                part: 2, name: "linear", slow: false,
+                help: "Solve root's equation as a linear function of humn",
+                solve: |input, _| Ok(part2_linear(input)?.to_string()),
+            },
+            Strategy {
+                part: 2, name: "binary_search", slow: false,
+                help: "Binary search for the value of humn",
+                solve: |input, _| Ok(part2(input)?.to_string()),
+            },
+        ]
+    }
+
+    // `size` operations between humn and root, plus the monkeys that
+    // don't depend on humn
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok(generate(rng, size))
+    }
+}
+
+fn part1(input: &str) -> Result<MonkeyNumber, ParseError> {
+    let monkeys = parse_input(input)?;
+    Ok(monkey_eval("root", &monkeys))
+}
+
+fn part2(input: &str) -> Result<MonkeyNumber, ParseError> {
+    let mut monkeys = parse_input(input)?;
+
+    // I'm tempted to try changing root's operation to Sub, so that it
+    // returns 0 for equality, and try changing humn's value to see how
+    // root's value changes.  Hopefully, there is a linear relationship,
+    // and I can binary search for the correct value for humn.
+    //
+    // Yes, increasing the value for humn seems to get closer to 0
+    // (for both the sample input and my full input).  So I'm going to
+    // keep doubling the value until it crosses zero, then binary search
+    // to find the exact value.
+
+    let root = monkeys.get_mut("root").unwrap();
+    if let MonkeyJob::Add(l, r) = root {
+        *root = MonkeyJob::Sub(l, r);
+    } else {
+        panic!("root should be addition!");
+    }
+
+    // Get humn's current value
+    let human_val = match monkeys.get("humn").ok_or_else(|| truncated(input, "a monkey named humn"))? {
+        MonkeyJob::Yell(v) => *v,
+        _ => panic!("humn should be a number (Yell)")
+    };
+
+    let mut low = human_val;
+    let mut high = human_val * 2;
+    let mut low_result = human_eval(low, &mut monkeys);
+    let mut high_result = human_eval(high, &mut monkeys);
+    while low_result.signum() == high_result.signum() {
+        low = high;
+        low_result = high_result;
+        high *= 2;
+        high_result = human_eval(high, &mut monkeys);
+    }
+    // println!("Somewhere between:");
+    // println!("    {} => {}", low, human_eval(low, &mut monkeys));
+    // println!("    {} => {}", high, human_eval(high, &mut monkeys));
+
+    let mut guess = (low + high) / 2;
+    while low < high {
+        guess = (low + high) / 2;
+        let guess_result = human_eval(guess, &mut monkeys);
+        if guess_result == 0 { break; }
+        if guess_result.signum() == low_result.signum() {
+            low = guess;
+        } else {
+            high = guess;
+        }
+    }
+    // There appear to be multiple valid answers!  Because of integer
+    // division, several consecutive values can make root's operands
+    // equal.  The puzzle wants the smallest of them.
+    while human_eval(guess - 1, &mut monkeys) == 0 {
+        guess -= 1;
+    }
+
+    Ok(guess)
+}
+
+fn part2_linear(input: &str) -> Result<f64, ParseError> {
+    let monkeys = parse_input(input)?;
+    let MonkeyJob::Add(left_name, right_name) = monkeys.get("root").unwrap() else {
+        panic!("root not add?")
+    };
+
+    let left = linear_eval(left_name, &monkeys);
+    let right = linear_eval(right_name, &monkeys);
+    
+    // I'm assuming that "humn" only appears in one of the two sides.
+    // In both the example and my input, it appears on the left.
+    // If it only appeared on the right, we could swap `left` and `right`.
+    // If it appears on both sides, then subtract one of the `m` values
+    // from both sides, and it will then be on only one side.
+    assert_eq!(right.m, 0.0);
+
+    // We have the equality:
+    //      left.m * humn + left.b = right.b
+    // Solve for `humn`:
+    //      left.m * humn          = right.b - left.b
+    //               humn          = (right.b - left.b) / left.m
+
+    Ok(((right.b - left.b) / left.m).round())
+}
+
+#[derive(Debug)]
+struct LinearExpression {
+    // Think of this as y = m * x + b
+    m: f64,     // Multiply the variable by this amount
+    b: f64,     // Add this constant
+}
+
+impl Add for LinearExpression {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        LinearExpression {
+            m: self.m + rhs.m,
+            b: self.b + rhs.b
+        }
+    }
+}
+
+impl Sub for LinearExpression {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        LinearExpression {
+            m: self.m - rhs.m,
+            b: self.b - rhs.b
+        }
+    }
+}
+
+impl Mul for LinearExpression {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if rhs.m == 0.0 {
+            // Multiplying on the right by a constant (rhs.b)
+            LinearExpression {
+                m: self.m * rhs.b,
+                b: self.b * rhs.b
+            }
+        } else if self.m == 0.0 {
+            // Multiplying on the left by a constant (self.b)
+            LinearExpression {
+                m: rhs.m * self.b,
+                b: rhs.b * self.b
+            }
+        } else {
+            panic!("non-linear");
+        }
+    }
+}
+
+impl Div for LinearExpression {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert_eq!(rhs.m, 0.0);
+        LinearExpression {
+            m: self.m / rhs.b,
+            b: self.b / rhs.b
+        }
+    }
+}
+
+//
+// Evaluate a part of the expression tree, using "humn" as the variable.
+//
+fn linear_eval(name: &str, monkeys: &HashMap<&str, MonkeyJob>) -> LinearExpression {
+    if name == "humn" {
+        return LinearExpression { m: 1.0, b: 0.0 };
+    }
+
+    match monkeys.get(name).unwrap() {
+        MonkeyJob::Yell(num) =>
+            LinearExpression { m: 0.0, b: *num as f64},
+        MonkeyJob::Add(left_name, right_name) =>
+            linear_eval(left_name, monkeys) + linear_eval(right_name, monkeys),
+        MonkeyJob::Sub(left_name, right_name) =>
+            linear_eval(left_name, monkeys) - linear_eval(right_name, monkeys),
+        MonkeyJob::Mul(left_name, right_name) =>
+            linear_eval(left_name, monkeys) * linear_eval(right_name, monkeys),
+        MonkeyJob::Div(left_name, right_name) =>
+            linear_eval(left_name, monkeys) / linear_eval(right_name, monkeys),
+    }
+}
+
+fn monkey_eval(name: &str, monkeys: &HashMap<&str, MonkeyJob>) -> MonkeyNumber {
+    let result = match monkeys.get(name).unwrap() {
+        MonkeyJob::Yell(num) => *num,
+        MonkeyJob::Add(left, right) =>
+            monkey_eval(left, monkeys) + monkey_eval(right, monkeys),
+        MonkeyJob::Sub(left, right) =>
+            monkey_eval(left, monkeys) - monkey_eval(right, monkeys),
+        MonkeyJob::Mul(left, right) =>
+            monkey_eval(left, monkeys) * monkey_eval(right, monkeys),
+        MonkeyJob::Div(left, right) =>
+            monkey_eval(left, monkeys) / monkey_eval(right, monkeys),
+    };
+    result
+}
+
+fn human_eval(human: MonkeyNumber, monkeys: &mut HashMap<&str, MonkeyJob>) -> MonkeyNumber {
+    // Change the value that "humn" yells, and evaluate "root"
+    monkeys.insert("humn", MonkeyJob::Yell(human));
+    monkey_eval("root", monkeys)
+}
+
+//
+// Parse lines like "root: pppw + sjmn" or "dbpl: 5".  Every monkey that
+// is named in an operation must have a line of its own, and so must "root".
+//
+fn parse_input(input: &str) -> Result<HashMap<&str, MonkeyJob<'_>>, ParseError> {
+    let mut monkeys = HashMap::<&str, MonkeyJob>::new();
+    let mut operands = Vec::new();
+
+    for line in lines(input) {
+        let (name, job) = line.split_once(": ")?;
+        if let Ok(num) = job.parse::<MonkeyNumber>() {
+            monkeys.insert(name, MonkeyJob::Yell(num));
+        } else {
+            let words: Vec<&str> = job.split(' ').collect();
+            let [left, operator, right]: [&str; 3] = words.try_into()
+                .map_err(|_| line.error(job, "expected a number, or an operation like \"a + b\""))?;
+            let job = match operator {
+                "+" => MonkeyJob::Add(left, right),
+                "-" => MonkeyJob::Sub(left, right),
+                "*" => MonkeyJob::Mul(left, right),
+                "/" => MonkeyJob::Div(left, right),
+                _ => return Err(line.error(operator, "expected +, -, * or /"))
+            };
+            monkeys.insert(name, job);
+            operands.push((line, left));
+            operands.push((line, right));
+        }
+    }
+
+    for (line, name) in operands {
+        if !monkeys.contains_key(name) {
+            return Err(line.error(name, "unknown monkey"));
+        }
+    }
+    if !monkeys.contains_key("root") {
+        return Err(truncated(input, "a monkey named root"));
+    }
+
+    Ok(monkeys)
+}
+
+type MonkeyNumber = i64;
+
+#[derive(Clone, Copy)]
+enum MonkeyJob<'a> {
+    Yell(MonkeyNumber),
+    Add(&'a str, &'a str),
+    Sub(&'a str, &'a str),
+    Mul(&'a str, &'a str),
+    Div(&'a str, &'a str),
+}
+
+//
+// Work up from the answer to part 2.  humn's side of root is a chain of
+// operations, each with a tree of monkeys that doesn't involve humn, and
+// the other side of root is another such tree.  Going up the chain, the
+// numbers stay positive, humn is only ever added to or multiplied, and
+// divisions are exact for the answer.  So the answer is the only one,
+// and it is bigger than humn's number (where the binary search starts).
+//
+fn generate(rng: &mut Rng, size: usize) -> String {
+    struct Monkeys<'a> {
+        rng: &'a mut Rng,
+        names: HashSet<String>,
+        lines: Vec<String>,
+    }
+
+    impl Monkeys<'_> {
+        fn name(&mut self) -> String {
+            loop {
+                let name = (0..4).map(|_| self.rng.lowercase()).collect::<String>();
+                if self.names.insert(name.clone()) {
+                    return name;
+                }
+            }
+        }
+
+        // A tree of monkeys whose root yells `value` (which is positive)
+        fn constant(&mut self, value: i64, depth: u32) -> String {
+            let name = self.name();
+            let divisors = (2..=20).filter(|d| value % d == 0).collect::<Vec<_>>();
+            let job = if depth == 0 || self.rng.chance(0.3) {
+                value.to_string()
+            } else {
+                let (left, operator, right) = match self.rng.below(4) {
+                    0 if value > 1 => {
+                        let left = self.rng.range(1..=value - 1);
+                        (left, '+', value - left)
+                    }
+                    1 if !divisors.is_empty() => {
+                        let right = *self.rng.choose(&divisors);
+                        (value / right, '*', right)
+                    }
+                    2 => {
+                        let right = self.rng.range(2..=5);
+                        (value * right, '/', right)
+                    }
+                    _ => {
+                        let right = self.rng.range(1..=20);
+                        (value + right, '-', right)
+                    }
+                };
+                let left = self.constant(left, depth - 1);
+                let right = self.constant(right, depth - 1);
+                format!("{left} {operator} {right}")
+            };
+            self.lines.push(format!("{name}: {job}"));
+            name
+        }
+    }
+
+    let mut monkeys = Monkeys { rng, names: HashSet::from(["root".to_string(), "humn".to_string()]), lines: Vec::new() };
+    let start = monkeys.rng.range(1..=1000);
+    monkeys.lines.push(format!("humn: {start}"));
+
+    // The numbers humn's ancestors yell, for the answer and for humn's number
+    let (mut answer, mut original) = (start + monkeys.rng.range(1..=1_000_000), start);
+    let mut human = "humn".to_string();
+    for _ in 0..size.max(1) {
+        let divisors = (2..=10).filter(|&d| answer % d == 0 && original >= d).collect::<Vec<_>>();
+        let depth = monkeys.rng.range(0..=3) as u32;
+        let job = match monkeys.rng.below(4) {
+            0 if answer < 100_000_000_000 => {
+                let other = monkeys.rng.range(2..=10);
+                answer *= other;
+                original *= other;
+                let other = monkeys.constant(other, depth);
+                if monkeys.rng.chance(0.5) { format!("{human} * {other}") } else { format!("{other} * {human}") }
+            }
+            1 if !divisors.is_empty() => {
+                let other = *monkeys.rng.choose(&divisors);
+                answer /= other;
+                original /= other;
+                format!("{human} / {}", monkeys.constant(other, depth))
+            }
+            2 if original > 1 => {
+                let other = monkeys.rng.range(1..=original - 1);
+                answer -= other;
+                original -= other;
+                format!("{human} - {}", monkeys.constant(other, depth))
+            }
+            _ => {
+                let other = monkeys.rng.range(1..=1000);
+                answer += other;
+                original += other;
+                let other = monkeys.constant(other, depth);
+                if monkeys.rng.chance(0.5) { format!("{human} + {other}") } else { format!("{other} + {human}") }
+            }
+        };
+        human = monkeys.name();
+        monkeys.lines.push(format!("{human}: {job}"));
+    }
+    let other = monkeys.constant(answer, 3);
+    monkeys.lines.push(format!("root: {human} + {other}"));
+
+    let mut lines = monkeys.lines;
+    rng.shuffle(&mut lines);
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[test]
+fn test_part2_alt_answer() {
+    let input = include_str!("../../../fixtures/day21/example.txt");
+    let mut monkeys = parse_input(input).unwrap();
+    monkeys.insert("humn", MonkeyJob::Yell(302));
+    let pppw = monkey_eval("pppw", &monkeys);
+    let sjmn = monkey_eval("sjmn", &monkeys);
+    dbg!(pppw);
+    dbg!(sjmn);
+    assert_eq!(pppw, sjmn);
+}
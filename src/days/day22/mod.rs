@@ -0,0 +1,532 @@
+use std::{collections::HashMap, fmt::Display};
+use anyhow::bail;
+use nom::{IResult, branch::alt, bytes::complete::tag, multi::many1, character, Parser};
+use part2::part2;
+use crate::{Animation, Param, Params, Rng, Solution};
+use crate::grid::{Point, SparseGrid};
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input, params.get("side")?)?.to_string())
+    }
+
+    // The example's cube has a side of 4.  (But its faces are laid out
+    // differently from the real input, which `part2` assumes.)
+    fn params(&self) -> &'static [Param] {
+        &[Param { name: "side", default: "50", help: "Length of a side of the cube (part 2)" }]
+    }
+
+    // A frame for each step and turn, leaving a trail behind
+    fn visualize(&self, part: u8, input: &str, params: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+        let (board, moves) = parse_input(input)?;
+        let mut trail = HashMap::new();
+        let on_move = |position, facing| {
+            trail.insert(position, facing);
+            animation.step(|| render_walk(&board, &trail, position));
+        };
+        let (position, _) = match part {
+            1 => walk(&board, &moves, on_move),
+            2 => {
+                let side = params.get("side")?;
+                part2::check_layout(&board, side)?;
+                part2::walk(board.clone(), &moves, side, on_move)
+            },
+            _ => bail!("Day 22 has no part {part}"),
+        };
+        animation.last(|| render_walk(&board, &trail, position));
+        Ok(())
+    }
+
+    // A board folded like the real input's cube, for the default side of
+    // 50, and `size` moves
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        const SIDE: usize = 50;
+        // The columns of faces in each row of faces
+        let faces = [1..3, 1..2, 0..2, 0..1];
+        let mut result = String::new();
+        for (y, columns) in (0..SIDE * 4).map(|y| (y, &faces[y / SIDE])) {
+            result.push_str(&" ".repeat(columns.start * SIDE));
+            for x in columns.start * SIDE .. columns.end * SIDE {
+                let start = (x, y) == (SIDE, 0);
+                result.push(if !start && rng.chance(0.1) { '#' } else { '.' });
+            }
+            result.push('\n');
+        }
+        result.push('\n');
+        for i in 0..size.max(1) {
+            if i > 0 {
+                result.push(*rng.choose(&['L', 'R']));
+            }
+            result.push_str(&rng.range(1..=50).to_string());
+        }
+        result.push('\n');
+        Ok(result)
+    }
+}
+
+fn part1(input: &str) -> Result<i32, ParseError> {
+    let (board, moves) = parse_input(input)?;
+    let (position, facing) = walk(&board, &moves, |_, _| {});
+    Ok(password(position, facing))
+}
+
+//
+// Follow the moves, and return the final position and facing.
+// `on_move` gets the position and facing after each step or turn.
+//
+fn walk(board: &SparseGrid<Board>, moves: &[Move], mut on_move: impl FnMut(Point, Facing)) -> (Point, Facing) {
+    // Find the starting position (the first Open in row #0)
+    let mut facing = Facing::Right;
+    let mut position = start(board);
+    on_move(position, facing);
+
+    // Follow the moves, with possible wrap-around
+    for m in moves {
+        match *m {
+            Move::Left => {
+                facing = facing.turn_left();
+                on_move(position, facing);
+            },
+            Move::Right => {
+                facing = facing.turn_right();
+                on_move(position, facing);
+            },
+            Move::Number(num) => {
+                for _ in 0 .. num {
+                    // Try to move one space in the current direction
+                    position = board_try_move(board, position, facing);
+                    on_move(position, facing);
+                }
+            },
+        }
+    }
+
+    (position, facing)
+}
+
+//
+// The board, with the path taken so far drawn as arrows (like the puzzle's
+// pictures), and the current position as '@'.
+//
+fn render_walk(board: &SparseGrid<Board>, trail: &HashMap<Point, Facing>, position: Point) -> String {
+    let (min, max) = board.bounds().unwrap();
+    let mut result = String::new();
+    for y in min.y ..= max.y {
+        let mut line = String::new();
+        for x in min.x ..= max.x {
+            let p = Point::new(x, y);
+            line.push(match (board.get(p), trail.get(&p)) {
+                _ if p == position => '@',
+                (_, Some(facing)) => facing.arrow(),
+                (Some(Board::Open), None) => '.',
+                (Some(Board::Wall), None) => '#',
+                (None, None) => ' ',
+            });
+        }
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+    result
+}
+
+// The first open tile in the top row
+fn start(board: &SparseGrid<Board>) -> Point {
+    board.iter()
+        .filter(|&(p, tile)| p.y == 0 && tile == &Board::Open)
+        .map(|(p, _)| p)
+        .min_by_key(|p| p.x)
+        .unwrap()
+}
+
+fn password(position: Point, facing: Facing) -> i32 {
+    1000 * (position.y + 1) + 4 * (position.x + 1) + (facing as i32)
+}
+
+fn board_try_move(board: &SparseGrid<Board>, position: Point, facing: Facing) -> Point {
+    let step = facing.step();
+
+    // If the new position is open, move there
+    match board.get(position + step) {
+        Some(&Board::Open) => { return position + step; },
+        Some(&Board::Wall) => { return position; },
+        None => {}
+    }
+
+    // Wrap around to the far side of the board.  Every row and column
+    // of the board is contiguous, so that is the end of the board in
+    // the opposite direction.
+    let wrapped = board.ray(position, -step).last().unwrap_or(position);
+    if board.get(wrapped) == Some(&Board::Open) {
+        wrapped
+    } else {
+        position
+    }
+}
+
+mod part2 {
+    use super::*;
+
+    struct State {
+        board: SparseGrid<Board>,
+        position: Point,
+        facing: Facing,
+        side: i32
+    }
+
+    // The faces that `try_move` knows how to fold, as (column, row) in
+    // units of the side: the real input's layout
+    const FACES: [(i32, i32); 6] = [(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)];
+
+    pub fn part2(input: &str, side_length: i32) -> anyhow::Result<i32> {
+        let (board, moves) = parse_input(input)?;
+        check_layout(&board, side_length)?;
+        let (position, facing) = walk(board, &moves, side_length, |_, _| {});
+        Ok(password(position, facing))
+    }
+
+    // Every tile has to be on one of `FACES`, and every face has to be
+    // full, or `try_move` would wrap around to somewhere off the board
+    pub fn check_layout(board: &SparseGrid<Board>, side_length: i32) -> anyhow::Result<()> {
+        if side_length <= 0 {
+            bail!("the side of the cube must be positive, not {side_length}");
+        }
+        let on_face = |p: Point| p.x >= 0 && p.y >= 0 && FACES.contains(&(p.x / side_length, p.y / side_length));
+        let cube_tiles = 6 * i128::from(side_length).pow(2);
+        if board.len() as i128 != cube_tiles || !board.points().all(on_face) {
+            bail!("the board isn't laid out like the real input's cube with a side of {side_length}, \
+                   and part 2 only knows how to fold that layout");
+        }
+        Ok(())
+    }
+
+    // Like `super::walk`, but wrapping around the cube
+    pub fn walk(board: SparseGrid<Board>, moves: &[Move], side_length: i32, mut on_move: impl FnMut(Point, Facing)) -> (Point, Facing) {
+        let position = start(&board);
+        let mut state = State {
+            board,
+            position,
+            facing: Facing::Right,
+            side: side_length
+        };
+        on_move(state.position, state.facing);
+
+        // Follow the moves, with possible wrap-around
+        for m in moves {
+            match *m {
+                Move::Left => {
+                    state.facing = state.facing.turn_left();
+                    on_move(state.position, state.facing);
+                },
+                Move::Right => {
+                    state.facing = state.facing.turn_right();
+                    on_move(state.position, state.facing);
+                },
+                Move::Number(num) => {
+                    for _ in 0 .. num {
+                        // Try to move one space in the current direction
+                        // with wrap-around
+                        try_move(&mut state);
+                        on_move(state.position, state.facing);
+                    }
+                },
+            }
+        }
+
+        (state.position, state.facing)
+    }
+
+    fn try_move(state: &mut State) {
+        let step = state.facing.step();
+
+        // If the new position is open, move there
+        match state.board.get(state.position + step) {
+            Some(&Board::Open) => {
+                state.position += step;
+                return;
+            },
+            Some(&Board::Wall) => {
+                return;
+            },
+            None => {}
+        }
+
+        // Wrap around.  Where we go depends on which cube face we are
+        // coming from.  We figure that out by dividing the row or col
+        // by the side length.  We use `%` to figure out how far along
+        // the side we are.
+        let Point { x: state_col, y: state_row } = state.position;
+        let rr = state_row % state.side;
+        let cc = state_col % state.side;
+
+        // These are the tentative spot to wrap around to
+        let row;
+        let mut col = state_col;
+        let mut facing = state.facing;
+
+        match state.facing {
+            Facing::Right => {
+                match state_row / state.side {
+                    0 => {
+                        // Face #2 => Face #5, upside down, going left
+                        row = state.side * 3 - 1 - rr;
+                        col = state.side * 2 - 1;
+                        facing = Facing::Left;
+                    },
+                    1 => {
+                        // Face #3 => Face #2, going up
+                        col = state.side * 2 + rr;
+                        row = state.side - 1;
+                        facing = Facing::Up;
+                    },
+                    2 => {
+                        // Face # 5 => Face #2, upside down
+                        row = state.side - 1 - rr;
+                        col = state.side * 3 - 1;
+                        facing = Facing::Left;
+                    },
+                    3 => {
+                        // Face #6 => Face #5, going up
+                        col = state.side + rr;
+                        row = state.side * 3 - 1;
+                        facing = Facing::Up;
+                    },
+                    _ => panic!("Invalid row")
+                }
+            },
+            Facing::Down => {
+                match state_col / state.side {
+                    0 => {
+                        // Face #6 => Face #2 (down)
+                        row = 0;
+                        col += state.side * 2;
+                    },
+                    1 => {
+                        // Face # 5 => Face #6, going left
+                        row = state.side * 3 + cc;
+                        col = state.side - 1;
+                        facing = Facing::Left;
+                    },
+                    2 => {
+                        // Face # 2 => Face # 3, going left
+                        row = state.side + cc;
+                        col = state.side * 2 - 1;
+                        facing = Facing::Left;
+                    },
+                    _ => panic!("Invalid col")
+                }
+            },
+            Facing::Left => {
+                match state_row / state.side {
+                    0 => {
+                        // Face #1 => Face #4, upside down, going right
+                        row = state.side * 3 - 1 - rr;
+                        col = 0;
+                        facing = Facing::Right;
+                    },
+                    1 => {
+                        // Face #3 => Face #4, going down
+                        col = rr;
+                        row = state.side * 2;
+                        facing = Facing::Down;
+                    },
+                    2 => {
+                        // Face #4 => Face #1, upside down, goint right
+                        row = state.side - 1 - rr;
+                        col = state.side;
+                        facing = Facing::Right;
+                    },
+                    3 => {
+                        // Face #6 => Face #1, going down
+                        col = state.side + rr;
+                        row = 0;
+                        facing = Facing::Down;
+                    },
+                    _ => panic!("Invalid row")
+                }
+            },
+            Facing::Up => {
+                match state_col / state.side {
+                    0 => {
+                        // Face #4 => Face #3, going right
+                        row = state.side + cc;
+                        col = state.side;
+                        facing = Facing::Right;
+                    },
+                    1 => {
+                        // Face #1 => Face #6, going right
+                        row = state.side * 3 + cc;
+                        col = 0;
+                        facing = Facing::Right;
+                    },
+                    2 => {
+                        // Face #2 => Face #6, going up
+                        col -= state.side * 2;
+                        row = state.side * 4 - 1;
+                    },
+                    _ => panic!("Invalid col")
+                }
+            },
+        }
+        match state.board.get(Point::new(col, row)) {
+            Some(&Board::Open) => {
+                state.position = Point::new(col, row);
+                state.facing = facing;
+            },
+            Some(&Board::Wall) => {
+                // Can't move to a wall
+            },
+            None => panic!("Invalid wrap around")
+        }
+    }
+}
+
+//
+// The board, then a blank line, then a line of moves.  Spaces on the
+// board are off the edge of the map.
+//
+fn parse_input(input: &str) -> Result<(SparseGrid<Board>, Vec<Move>), ParseError> {
+    let mut lines = lines(input);
+    for line in lines.by_ref().take_while(|line| !line.text.is_empty()) {
+        if let Some(i) = line.text.find(|ch| !matches!(ch, ' ' | '.' | '#')) {
+            let ch = line.text[i..].chars().next().unwrap();
+            return Err(line.error(&line.text[i..i+ch.len_utf8()], "expected ' ', '.' or '#'"));
+        }
+    }
+    let moves_line = lines.next().ok_or_else(|| truncated(input, "a blank line and then the moves"))?;
+
+    let board_str = input.split_once("\n\n").map_or("", |(board, _)| board);
+    let board = SparseGrid::parse(board_str, |ch| match ch {
+        '.' => Some(Board::Open),
+        '#' => Some(Board::Wall),
+        _ => None
+    });
+    if !board.iter().any(|(p, tile)| p.y == 0 && tile == &Board::Open) {
+        return Err(ParseError::new(1, 1, input.lines().next().unwrap_or(""), "expected an open tile in the top row"));
+    }
+
+    // Split `moves` into runs of digits or single letter
+    let moves = match parse_moves(moves_line.text) {
+        Ok(("", moves)) => moves,
+        Ok((rest, _)) => return Err(moves_line.error(rest, "expected L, R or a number")),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            return Err(moves_line.error(e.input, "expected L, R or a number"));
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers only"),
+    };
+
+    Ok((board, moves))
+}
+
+fn parse_moves(s: &str) -> IResult<&str,Vec<Move>> {
+    many1(alt((
+        tag("L").map(|_| Move::Left),
+        tag("R").map(|_| Move::Right),
+        character::complete::i32.map(Move::Number)
+    )))
+    .parse(s)
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Board {
+    Open,
+    Wall
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+enum Facing {
+    Right = 0,
+    Down = 1,
+    Left = 2,
+    Up = 3
+}
+
+impl Display for Facing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Facing::Right => write!(f, "Right"),
+            Facing::Down => write!(f, "Down"),
+            Facing::Left => write!(f, "Left"),
+            Facing::Up => write!(f, "Up"),
+        }
+    }
+}
+
+impl Facing {
+    fn arrow(&self) -> char {
+        match self {
+            Facing::Right => '>',
+            Facing::Down => 'v',
+            Facing::Left => '<',
+            Facing::Up => '^',
+        }
+    }
+
+    // The direction to move one space
+    fn step(&self) -> Point {
+        match self {
+            Facing::Right => Point::RIGHT,
+            Facing::Down => Point::DOWN,
+            Facing::Left => Point::LEFT,
+            Facing::Up => Point::UP,
+        }
+    }
+
+    fn turn_right(&self) -> Self {
+        use Facing::*;
+        match self {
+            Right => Down,
+            Down => Left,
+            Left => Up,
+            Up => Right
+        }
+    }
+
+    fn turn_left(&self) -> Self {
+        use Facing::*;
+        match self {
+            Right => Up,
+            Up => Left,
+            Left => Down,
+            Down => Right
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Move {
+    Number(i32),
+    Left,
+    Right
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../../fixtures/day22/example.txt");
+
+    #[test]
+    fn test_part2_layout() {
+        // The real input's layout, with a side of 1
+        let input = " ..\n .\n..\n.\n\n10R10R10L10L3\n";
+        assert!(part2(input, 1).is_ok());
+        assert_eq!(part2(input, 0).unwrap_err().to_string(), "the side of the cube must be positive, not 0");
+
+        // The example's faces are laid out differently, whatever the side
+        for side in [2, 3, 4, 50] {
+            assert!(part2(EXAMPLE, side).is_err(), "side {side}");
+            assert!(part2(input, side).is_err(), "side {side}");
+        }
+    }
+}
@@ -1,23 +1,48 @@
 use fxhash::FxHashSet as HashSet;
-use std::ops::{Add, AddAssign, Sub, Mul};
+use anyhow::bail;
+use crate::{Animation, Params, Rng, Solution};
+use crate::grid::{bounding_box, Point};
+use crate::parse::{lines, truncated, ParseError};
 
-fn main() {
-    let path = std::env::args().nth(1)
-        .unwrap_or_else(|| "src/bin/day23/input.txt".into());
-    let input = std::fs::read_to_string(path).unwrap();
-    let positions = parse_input(&input);
+pub struct Day23;
 
-    let now = std::time::Instant::now();
-    let result1 = part1(positions.clone());
-    let duration = now.elapsed().as_secs_f64();
-    println!("Part 1: {result1} (in {duration} seconds)");
-    assert_eq!(result1, 3882);
+impl Solution for Day23 {
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(&mut parse_input(input)?, |_| {}).to_string())
+    }
 
-    let now = std::time::Instant::now();
-    let result2 = part2(positions);
-    let duration = now.elapsed().as_secs_f64();
-    println!("Part 2: {result2} (in {duration} seconds)");
-    assert_eq!(result2, 1116);
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(&mut parse_input(input)?, |_| {}).to_string())
+    }
+
+    // A frame for the start, and after each round
+    fn visualize(&self, part: u8, input: &str, _params: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+        let mut positions = parse_input(input)?;
+        let on_round = |positions: &HashSet<Point>| animation.step(|| render_elves(positions));
+        match part {
+            1 => { part1(&mut positions, on_round); }
+            2 => { part2(&mut positions, on_round); }
+            _ => bail!("Day 23 has no part {part}"),
+        }
+        animation.last(|| render_elves(&positions));
+        Ok(())
+    }
+
+    // A square `size` positions across, about half of them elves
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let size = size.max(1);
+        let mut result = (0..size).map(|_| {
+            (0..size).map(|_| if rng.chance(0.5) { '#' } else { '.' }).chain(['\n']).collect::<String>()
+        }).collect::<String>();
+        if !result.contains('#') {
+            result.replace_range(0..1, "#");
+        }
+        Ok(result)
+    }
 }
 
 //
@@ -37,7 +62,7 @@ fn main() {
 //      Perhaps just a vec![0,1,2,3] that we iterate over and match
 //      on the value to do the direction-dependent logic.
 //
-fn part1(mut positions: HashSet<Point>) -> usize {
+fn part1(positions: &mut HashSet<Point>, mut on_round: impl FnMut(&HashSet<Point>)) -> usize {
     let mut directions = vec![
         Direction::North,
         Direction::South,
@@ -45,25 +70,18 @@ fn part1(mut positions: HashSet<Point>) -> usize {
         Direction::East
     ];
 
+    on_round(positions);
     for _ in 0 .. 10 {
-        one_round(&mut positions, &mut directions);
+        one_round(positions, &mut directions);
+        on_round(positions);
     }
 
     // Compute how many empty spaces within the tightest bounding rectangle
-    let mut positions_iter = positions.iter();
-    let Point{x: mut min_x, y: mut min_y} = positions_iter.next().unwrap();
-    let mut max_x = min_x;
-    let mut max_y = min_y;
-    for p in positions_iter {
-        min_x = min_x.min(p.x);
-        min_y = min_y.min(p.y);
-        max_x = max_x.max(p.x);
-        max_y = max_y.max(p.y);
-    }
-    (max_x - min_x + 1) as usize * (max_y - min_y + 1) as usize - positions.len()
+    let (min, max) = bounding_box(positions.iter()).unwrap();
+    (max.x - min.x + 1) as usize * (max.y - min.y + 1) as usize - positions.len()
 }
 
-fn part2(mut positions: HashSet<Point>) -> u32 {
+fn part2(positions: &mut HashSet<Point>, mut on_round: impl FnMut(&HashSet<Point>)) -> u32 {
     let mut directions = vec![
         Direction::North,
         Direction::South,
@@ -71,10 +89,12 @@ fn part2(mut positions: HashSet<Point>) -> u32 {
         Direction::East
     ];
 
+    on_round(positions);
     let mut rounds = 0;
     loop {
         rounds += 1;
-        let moved = one_round(&mut positions, &mut directions);
+        let moved = one_round(positions, &mut directions);
+        on_round(positions);
         if !moved {
             break;
         }
@@ -85,7 +105,10 @@ fn part2(mut positions: HashSet<Point>) -> u32 {
 
 // Neighbor offsets, from upper left, going clockwise, plus the first one repeated
 // (which will make it easier to get the three neighbors on a side)
-const NEIGHBORS:[(i32, i32);9] = [(-1,-1), (0,-1), (1,-1), (1,0), (1,1), (0,1), (-1,1), (-1, 0), (-1,-1)];
+const NEIGHBORS: [Point; 9] = [
+    Point::new(-1,-1), Point::new(0,-1), Point::new(1,-1), Point::new(1,0), Point::new(1,1),
+    Point::new(0,1), Point::new(-1,1), Point::new(-1,0), Point::new(-1,-1)
+];
 
 // Do one round of movements.
 // Return true if any elf moved.
@@ -122,13 +145,13 @@ fn one_round(positions: &mut HashSet<Point>, directions:&mut Vec<Direction>) ->
         let mut new_elf = *elf;
 
         // Determine its proposed position (using `previous`)
-        if NEIGHBORS[..8].iter().all(|n| !previous.contains(&(elf+n)))
+        if NEIGHBORS[..8].iter().all(|n| !previous.contains(&(*elf + *n)))
         {
             // The elf stays where it is
         } else {
             for dir in directions.iter().copied() {
                 let neighbors_index = (dir as usize) * 2;
-                if NEIGHBORS[neighbors_index..][..3].iter().all(|n| !previous.contains(&(elf+n))) {
+                if NEIGHBORS[neighbors_index..][..3].iter().all(|n| !previous.contains(&(*elf + *n))) {
                     new_elf += NEIGHBORS[neighbors_index + 1];
                     break;
                 }
@@ -157,81 +180,37 @@ fn one_round(positions: &mut HashSet<Point>, directions:&mut Vec<Direction>) ->
     moves > 0
 }
 
-fn parse_input(input: &str) -> HashSet<Point> {
+fn parse_input(input: &str) -> Result<HashSet<Point>, ParseError> {
     let mut positions = HashSet::<Point>::default();
-    for (y, line) in input.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if ch == '#' {
-                positions.insert(Point{x: x as i32, y: y as i32});
-            }
-        }
-    }
-    positions
-}
-
-#[allow(dead_code)]
-fn print_elves(positions: &HashSet<Point>) {
-    let min_x = positions.iter().map(|p| p.x).min().unwrap();
-    let max_x = positions.iter().map(|p| p.x).max().unwrap();
-    let min_y = positions.iter().map(|p| p.y).min().unwrap();
-    let max_y = positions.iter().map(|p| p.y).max().unwrap();
-
-    for y in min_y ..= max_y {
-        for x in min_x ..= max_x {
-            if positions.contains(&Point{x,y}) {
-                print!("#");
-            } else {
-                print!(".");
+    for (y, line) in lines(input).enumerate() {
+        for (x, (i, ch)) in line.text.char_indices().enumerate() {
+            match ch {
+                '#' => { positions.insert(Point{x: x as i32, y: y as i32}); },
+                '.' => {},
+                _ => return Err(line.error(&line.text[i..i+ch.len_utf8()], "expected '#' or '.'"))
             }
         }
-        println!();
-    }
-}
-
-#[derive(Hash, Clone, Copy, PartialEq, Eq)]
-struct Point {
-    x: i32,
-    y: i32,
-}
-
-impl Add<&(i32,i32)> for &Point {
-    type Output = Point;
-
-    fn add(self, rhs: &(i32,i32)) -> Self::Output {
-        Point{ x: self.x + rhs.0, y: self.y + rhs.1 }
     }
-}
-
-impl Add<(i32,i32)> for Point {
-    type Output = Point;
-
-    fn add(self, rhs: (i32,i32)) -> Self::Output {
-        Point{x: self.x + rhs.0, y: self.y + rhs.1 }
+    if positions.is_empty() {
+        return Err(truncated(input, "at least one elf"));
     }
+    Ok(positions)
 }
 
-impl AddAssign<(i32,i32)> for Point {
-    fn add_assign(&mut self, rhs: (i32,i32)) {
-        self.x += rhs.0;
-        self.y += rhs.1;
-    }
-}
-
-impl Sub for Point {
-    type Output = Point;
+// Elves are '#', within the smallest rectangle that contains them
+fn render_elves(positions: &HashSet<Point>) -> String {
+    let (min, max) = bounding_box(positions).unwrap();
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Point{ x: self.x - rhs.x, y: self.y - rhs.y }
+    let mut result = String::new();
+    for y in min.y ..= max.y {
+        for x in min.x ..= max.x {
+            result.push(if positions.contains(&Point{x,y}) { '#' } else { '.' });
+        }
+        result.push('\n');
     }
+    result
 }
 
-impl Mul<i32> for Point {
-    type Output = Point;
-
-    fn mul(self, rhs: i32) -> Self::Output {
-        Point{ x: self.x * rhs, y: self.y * rhs }
-    }
-}
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Direction {
     North,
@@ -240,36 +219,6 @@ enum Direction {
     West,
 }
 
-#[test]
-fn test_part1() {
-    let input = "\
-....#..
-..###.#
-#...#.#
-.#...##
-#.###..
-##.#.##
-.#..#..
-";
-    let positions = parse_input(input);
-    assert_eq!(part1(positions), 110);
-}
-
-#[test]
-fn test_part2() {
-    let input = "\
-....#..
-..###.#
-#...#.#
-.#...##
-#.###..
-##.#.##
-.#..#..
-";
-    let positions = parse_input(input);
-    assert_eq!(part2(positions), 20);
-}
-
 #[test]
 fn test_rounds_mini() {
     let input = "\
@@ -280,7 +229,7 @@ fn test_rounds_mini() {
     ..##.\n\
     .....\n";
 
-    let mut positions = parse_input(input);
+    let mut positions = parse_input(input).unwrap();
     let mut directions = vec![
         Direction::North,
         Direction::South,
@@ -288,10 +237,10 @@ fn test_rounds_mini() {
         Direction::East
     ];
     println!("== Initial Position ==");
-    print_elves(&positions);
+    print!("{}", render_elves(&positions));
     for i in 1..=3 {
         println!("== Round {i} ==");
         one_round(&mut positions, &mut directions);
-        print_elves(&positions);
+        print!("{}", render_elves(&positions));
     }
 }
@@ -0,0 +1,340 @@
+use pathfinding::prelude::{astar, bfs};
+use anyhow::{anyhow, bail};
+use crate::{Animation, Params, Rng, Solution};
+use crate::grid::Point;
+use crate::search::SearchStats;
+use crate::parse::{lines, truncated, ParseError};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+
+    // A frame for each minute of the quickest route
+    fn visualize(&self, part: u8, input: &str, _params: &Params, animation: &mut Animation) -> anyhow::Result<()> {
+        let valley = Valley::new(input)?;
+        let trips = match part {
+            1 => vec![(valley.start, valley.end)],
+            2 => vec![(valley.start, valley.end), (valley.end, valley.start), (valley.start, valley.end)],
+            _ => bail!("Day 24 has no part {part}"),
+        };
+        let mut time = 0;
+        let mut route = Vec::new();
+        for (from, to) in trips {
+            let trip = valley.route(from, to, time)?;
+            time = trip.last().unwrap().time;
+            // Each trip starts where the previous one ended
+            if !route.is_empty() {
+                route.pop();
+            }
+            route.extend(trip);
+        }
+        for state in &route {
+            animation.step(|| valley.render(state));
+        }
+        animation.last(|| valley.render(route.last().unwrap()));
+        Ok(())
+    }
+
+    // A valley `size` wide (at least 2), and a fifth as tall, about half
+    // full of blizzards.  No blizzard goes up or down through the start
+    // or the end.  Valleys that can't be crossed are thrown away.
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        let width = size.max(2);
+        let height = (width / 5).max(1);
+        loop {
+            let mut input = format!("#.{}\n", "#".repeat(width));
+            for _ in 0..height {
+                input.push('#');
+                for x in 0..width {
+                    let directions: &[char] = if x == 0 || x == width - 1 { &['<', '>'] } else { &['<', '>', '^', 'v'] };
+                    input.push(if rng.chance(0.5) { *rng.choose(directions) } else { '.' });
+                }
+                input.push_str("#\n");
+            }
+            input.push_str(&format!("{}.#\n", "#".repeat(width)));
+
+            let valley = Valley::new(&input)?;
+            if valley.reachable(valley.start, valley.end) && valley.reachable(valley.end, valley.start) {
+                return Ok(input);
+            }
+        }
+    }
+}
+
+//
+// Part 1
+//
+// Find the quickest way from the start to the end.
+//
+fn part1(input: &str) -> anyhow::Result<i32> {
+    let valley = Valley::new(input)?;
+    valley.crossing(valley.start, valley.end, 0)
+}
+
+//
+// Part 2
+//
+// Go from the start to the end, back to the start, and then back to the end
+// again.  Each trip starts at the time the previous one finished.
+//
+fn part2(input: &str) -> anyhow::Result<i32> {
+    let valley = Valley::new(input)?;
+    let steps1 = valley.crossing(valley.start, valley.end, 0)?;
+    let steps2 = valley.crossing(valley.end, valley.start, steps1)?;
+    let steps3 = valley.crossing(valley.start, valley.end, steps1 + steps2)?;
+    Ok(steps1 + steps2 + steps3)
+}
+
+struct Valley {
+    width: i32,
+    height: i32,
+    rows: Vec<Vec<Blizzard>>,
+    cols: Vec<Vec<Blizzard>>,
+    start: Point,
+    end: Point,
+}
+
+impl Valley {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let (width, height, rows, cols) = parse_input(input)?;
+        let start = Point::new(0, -1);
+        let end = Point::new(width-1, height);
+        Ok(Self { width, height, rows, cols, start, end })
+    }
+
+    fn in_bounds(&self, &Point{x,y}: &Point) -> bool {
+        x >= 0 && x < self.width && y >= 0 && y < self.height
+    }
+
+    fn empty_at(&self, &Point{x,y}: &Point, time: i32) -> bool {
+        self.rows[y as usize].iter().all(|b| b.position(time, self.width) != x) &&
+        self.cols[x as usize].iter().all(|b| b.position(time, self.height) != y)
+    }
+
+    //
+    // Return the number of minutes it takes to get from `from` to `to`,
+    // leaving `from` at time `time`.
+    //
+    fn crossing(&self, from: Point, to: Point, time: i32) -> anyhow::Result<i32> {
+        Ok(self.route(from, to, time)?.len() as i32 - 1)
+    }
+
+    //
+    // The states along the quickest route, including the start and end.
+    // The blizzards repeat every `period` minutes, so a quickest route
+    // never comes back to the same place at the same point in the cycle,
+    // which limits how long it can take.  Without the limit, the search
+    // would go on forever if there were no way across.
+    //
+    fn route(&self, from: Point, to: Point, time: i32) -> anyhow::Result<Vec<State>> {
+        let deadline = time.saturating_add((self.width * self.height + 2).saturating_mul(self.period()));
+        let success = |state: &State| state.position == to;
+        let heuristic = |state: &State| state.position.manhattan(&to);
+        let stats = SearchStats::new(module_path!(), format!("({},{}) to ({},{}) from minute {time}", from.x, from.y, to.x, to.y));
+        let successors = |state: &State| -> Vec<(State, i32)> {
+            stats.pop();
+            let time = state.time + 1;
+            let mut result = Vec::new();
+            if time > deadline {
+                stats.prune("too late");
+                return result;
+            }
+
+            for movement in [Point::ZERO, Point::LEFT, Point::RIGHT, Point::UP, Point::DOWN] {
+                let position = state.position + movement;
+                if position == self.end || position == self.start {
+                    result.push((State{ position, time }, 1));
+                } else if !self.in_bounds(&position) {
+                    stats.prune("wall");
+                } else if !self.empty_at(&position, time) {
+                    stats.prune("blizzard");
+                } else {
+                    result.push((State{ position, time }, 1));
+                }
+            }
+
+            for (state, _) in &result {
+                stats.push_state(state);
+            }
+            result
+        };
+
+        let (path, _) = astar(
+                &State{ position: from, time },
+                successors, heuristic, success
+            ).ok_or_else(|| anyhow!("there is no way from ({},{}) to ({},{})", from.x, from.y, to.x, to.y))?;
+        stats.finish();
+        Ok(path)
+    }
+
+    // How often the blizzards repeat: lcm(width, height) minutes
+    fn period(&self) -> i32 {
+        let gcd = |mut a: i32, mut b: i32| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        };
+        self.width / gcd(self.width, self.height) * self.height
+    }
+
+    //
+    // Whether there is any way from `from` to `to`, leaving at time 0.
+    // The blizzards repeat every lcm(width, height) minutes, so time only
+    // needs to be counted that far.  (And since the expedition can wait
+    // at `from` for as long as it likes, that answers it for any time.)
+    //
+    fn reachable(&self, from: Point, to: Point) -> bool {
+        let period = self.period();
+        let successors = |state: &State| -> Vec<State> {
+            let time = (state.time + 1) % period;
+            [Point::ZERO, Point::LEFT, Point::RIGHT, Point::UP, Point::DOWN].into_iter()
+                .map(|movement| state.position + movement)
+                .filter(|position| *position == self.start || *position == self.end ||
+                    (self.in_bounds(position) && self.empty_at(position, time)))
+                .map(|position| State{ position, time })
+                .collect()
+        };
+        bfs(&State{ position: from, time: 0 }, successors, |state| state.position == to).is_some()
+    }
+
+    //
+    // Draw the valley like the puzzle does: the expedition is 'E', a cell
+    // with one blizzard shows its direction, and a cell with several
+    // shows how many.
+    //
+    fn render(&self, state: &State) -> String {
+        let mut result = String::new();
+        for y in -1 ..= self.height {
+            for x in -1 ..= self.width {
+                let p = Point::new(x, y);
+                if p == state.position {
+                    result.push('E');
+                } else if p == self.start || p == self.end {
+                    result.push('.');
+                } else if !self.in_bounds(&p) {
+                    result.push('#');
+                } else {
+                    let horizontal = self.rows[y as usize].iter()
+                        .filter(|b| b.position(state.time, self.width) == x)
+                        .map(|b| if b.direction < 0 { '<' } else { '>' });
+                    let vertical = self.cols[x as usize].iter()
+                        .filter(|b| b.position(state.time, self.height) == y)
+                        .map(|b| if b.direction < 0 { '^' } else { 'v' });
+                    let blizzards = horizontal.chain(vertical).collect::<Vec<_>>();
+                    result.push(match blizzards.len() {
+                        0 => '.',
+                        1 => blizzards[0],
+                        n => char::from_digit(n as u32, 10).unwrap(),
+                    });
+                }
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct State {
+    position: Point,
+    time: i32
+}
+
+//
+//  parse_input
+//
+// Parse the input string and return information about the blizzard
+// positions and directions, and the overall size of the area.
+//
+// For ease of computing future blizzard positions, the coordinate system
+// is offset such that the first line of input is row -1, and the first
+// column of each line is column -1.  That way, the inner area has rows
+// numbered 0..height, and columns numbered 0..width.
+//
+// The output is a tuple:
+//      0: width of the inner area (number of columns)
+//      1: height of the inner area (number of rows)
+//      2: rows of blizzards that move horizontally
+//      3: columns of blizzards that move vertically
+//
+type Blizzards = Vec<Vec<Blizzard>>;
+
+fn parse_input(input: &str) -> Result<(i32, i32, Blizzards, Blizzards), ParseError> {
+    // First, figure out the dimensions of the inner area
+    let lines: Vec<_> = lines(input).collect();
+    if lines.len() < 3 {
+        return Err(truncated(input, "walls around at least one row"));
+    }
+    let height = lines.len() - 2;
+    let width = lines[0].text.len().saturating_sub(2);
+    if width == 0 {
+        return Err(lines[0].invalid("expected walls around at least one column"));
+    }
+
+    // Create the vectors to store the blizzards
+    let mut rows = Vec::with_capacity(height);
+    let mut cols = Vec::with_capacity(width);
+    rows.resize_with(height, Vec::new);
+    cols.resize_with(width, Vec::new);
+
+    let bottom = lines[height + 1];
+    if bottom.text.chars().count() != width + 2 {
+        return Err(bottom.invalid(format!("expected {} characters", width + 2)));
+    }
+
+    for (y,line) in lines[1..=height].iter().enumerate() {
+        if line.text.chars().count() != width + 2 {
+            return Err(line.invalid(format!("expected {} characters", width + 2)));
+        }
+        for (x,(i,ch)) in line.text.char_indices().skip(1).take(width).enumerate() {
+            match ch {
+                '^' => cols[x].push(Blizzard::new(y as i32, -1)),
+                'v' => cols[x].push(Blizzard::new(y as i32,  1)),
+                '<' => rows[y].push(Blizzard::new(x as i32, -1)),
+                '>' => rows[y].push(Blizzard::new(x as i32,  1)),
+                '.' => (),
+                _ => return Err(line.error(&line.text[i..i+ch.len_utf8()], "expected '^', 'v', '<', '>' or '.'"))
+            }
+        }
+    }
+
+    Ok((width as i32, height as i32, rows, cols))
+}
+
+struct Blizzard {
+    initial: i32,
+    direction: i32,      // -1 or +1
+}
+
+impl Blizzard {
+    fn new(initial: i32, direction: i32) -> Self {
+        Self { initial, direction }
+    }
+
+    fn position(&self, time: i32, modulus: i32) -> i32 {
+        let mut answer = (self.initial + time * self.direction) % modulus;
+        if answer < 0 {
+            answer += modulus;
+        }
+        answer
+    }
+}
+
+// A blizzard going up or down in a valley one row tall never moves
+#[test]
+fn test_no_way_across() {
+    let input = "#.###\n#.^.#\n###.#\n";
+    assert_eq!(part1(input).unwrap_err().to_string(), "there is no way from (0,-1) to (2,1)");
+}
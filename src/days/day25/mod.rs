@@ -0,0 +1,117 @@
+use crate::{Params, Rng, Solution};
+use crate::parse::{lines, Line, ParseError};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn part1(&self, input: &str, _params: &Params) -> anyhow::Result<String> {
+        Ok(part1(input)?)
+    }
+
+    // There is no puzzle for part 2 on the last day.
+    fn parts(&self) -> u8 {
+        1
+    }
+
+    // `size` fuel requirements
+    fn generate(&self, rng: &mut Rng, size: usize, _params: &Params) -> anyhow::Result<String> {
+        Ok((0..size.max(1)).map(|_| from_decimal(rng.range(1..=1_000_000_000_000)) + "\n").collect())
+    }
+}
+
+fn part1(input: &str) -> Result<String, ParseError> {
+    let result = lines(input).map(|line| to_decimal(&line)).sum::<Result<i64, _>>()?;
+    Ok(from_decimal(result))
+}
+
+fn to_decimal(line: &Line) -> Result<i64, ParseError> {
+    if line.text.is_empty() {
+        return Err(line.invalid("expected a SNAFU number"));
+    }
+    let mut result: i64 = 0;
+    for (i, ch) in line.text.char_indices() {
+        let digit = match ch {
+            '2' => 2,
+            '1' => 1,
+            '0' => 0,
+            '-' => -1,
+            '=' => -2,
+            _ => return Err(line.error(&line.text[i..i+ch.len_utf8()], "invalid digit"))
+        };
+        result = result.checked_mul(5).and_then(|r| r.checked_add(digit))
+            .ok_or_else(|| line.invalid("number is too large"))?;
+    }
+    Ok(result)
+}
+
+fn from_decimal(mut num: i64) -> String {
+    if num == 0 {
+        return "0".to_string();
+    }
+    let mut digits = Vec::<i64>::new();
+    while num != 0 {
+        let mut q = num / 5;
+        let mut r = num % 5;
+        if r > 2 {
+            r -= 5;
+            q += 1;
+        } else if r < -2 {
+            r += 5;
+            q -= 1;
+        }
+        digits.push(r);
+        num = q;
+    }
+
+    digits.iter().rev().map(|d| {
+        match d {
+            2 => '2',
+            1 => '1',
+            0 => '0',
+            -1 => '-',
+            -2 => '=',
+            _ => panic!("invalid digit")
+        }
+    }).collect::<String>()
+}
+
+#[test]
+fn test_to_decimal() {
+    let to_decimal = |s| to_decimal(&Line::new(1, s));
+    assert_eq!(to_decimal("1=-0-2"), Ok(1747));
+    assert_eq!(to_decimal("12111"), Ok(906));
+    assert_eq!(to_decimal("2=01"), Ok(201));
+    assert_eq!(to_decimal("1=-1="), Ok(353));
+    assert_eq!(to_decimal("-2"), Ok(-3));
+}
+
+#[test]
+fn test_invalid_digit() {
+    let error = part1("1=-0-2\n12311\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 3: invalid digit: \"3\"");
+}
+
+#[test]
+fn test_from_decimal() {
+    assert_eq!(from_decimal(1), "1");
+    assert_eq!(from_decimal(2), "2");
+    assert_eq!(from_decimal(3), "1=");
+    assert_eq!(from_decimal(4), "1-");
+    assert_eq!(from_decimal(5), "10");
+    assert_eq!(from_decimal(6), "11");
+    assert_eq!(from_decimal(7), "12");
+    assert_eq!(from_decimal(8), "2=");
+    assert_eq!(from_decimal(9), "2-");
+    assert_eq!(from_decimal(10), "20");
+    assert_eq!(from_decimal(15), "1=0");
+    assert_eq!(from_decimal(20), "1-0");
+    assert_eq!(from_decimal(2022), "1=11-2");
+    assert_eq!(from_decimal(12345), "1-0---0");
+    assert_eq!(from_decimal(314159265), "1121-1110-1=0");
+    assert_eq!(from_decimal(0), "0");
+    assert_eq!(from_decimal(-3), "-2");
+}
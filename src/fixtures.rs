@@ -0,0 +1,134 @@
+use std::{collections::BTreeMap, path::{Path, PathBuf}};
+use anyhow::{bail, Context};
+use serde::Deserialize;
+use crate::{Param, Params};
+
+//
+// Example inputs, and other small inputs with known answers.
+//
+// Each day has a directory of fixtures, and each fixture is a puzzle
+// input plus a TOML "sidecar" with the same name:
+//
+//      fixtures/
+//          day15/
+//              example.txt
+//              example.toml
+//
+// The sidecar has the expected answers, and the values of any of the
+// day's parameters that are different for this input:
+//
+//      part1 = 26
+//      part2 = 56000011
+//
+//      [params]
+//      row = 10
+//      bound = 20
+//
+// Answers can be numbers or strings.  A part without an expected
+// answer isn't checked; say why in a comment.
+//
+// Every (non-slow) strategy is checked against the expected answers,
+// except those named in `skip_strategies`.
+//
+pub const DEFAULT_ROOT: &str = "fixtures";
+
+#[derive(Debug)]
+pub struct Fixture {
+    pub day: u8,
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub params: Vec<(String, String)>,
+    pub skip_strategies: Vec<String>,
+}
+
+impl Fixture {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None
+        }
+    }
+
+    // The day's parameters, with this fixture's values.
+    pub fn params(&self, declared: &[Param]) -> anyhow::Result<Params> {
+        Params::new(declared, self.params.iter().map(|(name, value)| (name, value.clone())))
+            .with_context(|| format!("{}", self.path.display()))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Sidecar {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+    #[serde(default)]
+    skip_strategies: Vec<String>,
+}
+
+//
+// Load every fixture under `root`, in order by day and then name.
+// Every input must have a sidecar.
+//
+pub fn load_all(root: &Path) -> anyhow::Result<Vec<Fixture>> {
+    let mut fixtures = Vec::new();
+    for day in 1..=25 {
+        let dir = root.join(format!("day{day:02}"));
+        if dir.is_dir() {
+            fixtures.extend(load_day(&dir, day)?);
+        }
+    }
+    Ok(fixtures)
+}
+
+fn load_day(dir: &Path, day: u8) -> anyhow::Result<Vec<Fixture>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    paths.into_iter().map(|path| {
+        let name = path.file_stem().unwrap().to_string_lossy().to_string();
+        let input = std::fs::read_to_string(&path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        let sidecar_path = path.with_extension("toml");
+        let sidecar = std::fs::read_to_string(&sidecar_path)
+            .with_context(|| format!("reading {}", sidecar_path.display()))?;
+        let sidecar: Sidecar = toml::from_str(&sidecar)
+            .with_context(|| format!("parsing {}", sidecar_path.display()))?;
+
+        let answer = |value: Option<toml::Value>| value.map(to_string).transpose();
+        let params = sidecar.params.into_iter()
+            .map(|(name, value)| Ok((name, to_string(value)?)))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .with_context(|| format!("parsing {}", sidecar_path.display()))?;
+        Ok(Fixture {
+            day,
+            name,
+            input,
+            part1: answer(sidecar.part1).with_context(|| format!("parsing {}", sidecar_path.display()))?,
+            part2: answer(sidecar.part2).with_context(|| format!("parsing {}", sidecar_path.display()))?,
+            params,
+            skip_strategies: sidecar.skip_strategies,
+            path,
+        })
+    }).collect()
+}
+
+// Answers and parameters can be written as numbers or strings.
+fn to_string(value: toml::Value) -> anyhow::Result<String> {
+    match value {
+        toml::Value::String(s) => Ok(s),
+        toml::Value::Integer(n) => Ok(n.to_string()),
+        other => bail!("expected a string or integer, got {other}")
+    }
+}
@@ -0,0 +1,126 @@
+use std::ops::RangeInclusive;
+use anyhow::Context;
+use crate::{solution, Params};
+#[cfg(test)]
+use crate::solutions;
+
+//
+// Random puzzle inputs, for stress testing and benchmarking at sizes
+// other than the one real input we have.
+//
+// Each day implements `Solution::generate`, which writes an input in
+// that day's grammar.  `size` is a rough measure of how big the input
+// is (lines, elves, monkeys, the width of a grid, ...); each day's
+// generator says what it means for that day.  The inputs are also
+// valid puzzles: the answers exist and don't depend on the strategy.
+// They are made for the parameters given (usually the defaults); day 15,
+// for example, hides the distress beacon within its `bound`.
+//
+// The inputs only depend on the seed, the size and the parameters, on
+// every platform and with every version of our dependencies, which is
+// why there is a small generator here, rather than using the `rand`
+// crate.
+//
+pub fn generate(day: u8, seed: u64, size: usize, params: &Params) -> anyhow::Result<String> {
+    let solution = solution(day).with_context(|| format!("no solution for day {day}"))?;
+    solution.generate(&mut Rng::new(seed), size, params)
+}
+
+//
+// SplitMix64.  It is tiny, fast, and passes BigCrush, which is plenty
+// for making up puzzle inputs.
+//
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // A number in 0..n.  (The bias is at most n / 2**64.)
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "can't pick from an empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    // A number in the inclusive range
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "can't pick from an empty range");
+        let width = (end - start) as u64 as u128 + 1;
+        start.wrapping_add(((self.next_u64() as u128 * width) >> 64) as i64)
+    }
+
+    // True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    // A random lowercase letter
+    pub fn lowercase(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+#[test]
+fn test_reproducible() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let xs = (0..10).map(|_| a.next_u64()).collect::<Vec<_>>();
+    let ys = (0..10).map(|_| b.next_u64()).collect::<Vec<_>>();
+    assert_eq!(xs, ys);
+    // The first output of SplitMix64 seeded with 0
+    assert_eq!(Rng::new(0).next_u64(), 0xE220A8397B1DCDAF);
+}
+
+#[test]
+fn test_range() {
+    let mut rng = Rng::new(1);
+    let mut seen = [false; 7];
+    for _ in 0..1000 {
+        let n = rng.range(-3..=3);
+        assert!((-3..=3).contains(&n));
+        seen[(n + 3) as usize] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+    assert_eq!(rng.range(5..=5), 5);
+    assert!((0..10).contains(&rng.below(10)));
+}
+
+#[test]
+fn test_generated_inputs() {
+    // Small inputs, so that the slow days are quick in debug builds
+    for solution in solutions() {
+        for seed in 1..=2 {
+            let params = Params::defaults(solution.params());
+            let input = solution.generate(&mut Rng::new(seed), 10, &params).unwrap();
+            assert_eq!(input, solution.generate(&mut Rng::new(seed), 10, &params).unwrap(), "day {} isn't reproducible", solution.day());
+            for part in 1..=solution.parts() {
+                if let Err(error) = solution.solve(part, &input, &params) {
+                    panic!("day {} part {part} failed on seed {seed}: {error}\n{input}", solution.day());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,493 @@
+use std::{fmt::Display, ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign}};
+use fxhash::FxHashMap as HashMap;
+use crate::parse::{lines, ParseError};
+
+//
+// Two-dimensional boards, like the ones in many of the puzzles.
+//
+// Coordinates are a `Point`, where `x` is the column and `y` is the row.
+// Rows are numbered from the top of the input, so "up" is negative `y`.
+//
+// `Grid<T>` is a dense rectangle of cells, from (0,0) to (width-1,height-1).
+// `SparseGrid<T>` only stores the cells that have something in them, and
+// can use any coordinates (including negative ones).
+//
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    // The directions to the 4 orthogonal neighbors, clockwise from up.
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+    // The directions to the 4 diagonal neighbors, clockwise from upper left.
+    pub const DIAGONAL: [Point; 4] = [
+        Point::new(-1, -1), Point::new(1, -1), Point::new(1, 1), Point::new(-1, 1)
+    ];
+
+    // The directions to all 8 neighbors, clockwise from upper left.
+    pub const ALL_AROUND: [Point; 8] = [
+        Point::new(-1, -1), Point::new(0, -1), Point::new(1, -1), Point::new(1, 0),
+        Point::new(1, 1), Point::new(0, 1), Point::new(-1, 1), Point::new(-1, 0),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Point::ORTHOGONAL.into_iter().map(move |d| self + d)
+    }
+
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Point::ALL_AROUND.into_iter().map(move |d| self + d)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+//
+// The smallest rectangle containing all of the points, as its upper left
+// and lower right corners (inclusive).  None if there are no points.
+//
+pub fn bounding_box<'a>(points: impl IntoIterator<Item = &'a Point>) -> Option<(Point, Point)> {
+    points.into_iter().fold(None, |bounds, p| match bounds {
+        None => Some((*p, *p)),
+        Some((min, max)) => Some((
+            Point::new(min.x.min(p.x), min.y.min(p.y)),
+            Point::new(max.x.max(p.x), max.y.max(p.y)),
+        ))
+    })
+}
+
+//
+// A dense, rectangular grid of cells, stored a row at a time.
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: i32, height: i32, value: T) -> Self
+    where T: Clone
+    {
+        assert!(width >= 0 && height >= 0);
+        Grid { width, height, cells: vec![value; (width * height) as usize] }
+    }
+
+    //
+    // Parse a map with one character per cell, and one line per row.
+    // All of the lines must be the same length.  `f` returns None for
+    // characters that aren't allowed in the map.
+    //
+    pub fn parse<F>(input: &str, mut f: F) -> Result<Self, ParseError>
+    where F: FnMut(char) -> Option<T>
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in lines(input) {
+            let before = cells.len();
+            for (i, ch) in line.text.char_indices() {
+                let cell = f(ch).ok_or_else(|| {
+                    line.error(&line.text[i..i+ch.len_utf8()], "unexpected character")
+                })?;
+                cells.push(cell);
+            }
+            let length = cells.len() - before;
+            match width {
+                None => width = Some(length),
+                Some(width) if width != length => {
+                    return Err(line.invalid(format!("expected {width} cells, found {length}")));
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+        Ok(Grid { width: width.unwrap_or(0) as i32, height, cells })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point) -> bool {
+        p.x >= 0 && p.x < self.width && p.y >= 0 && p.y < self.height
+    }
+
+    fn index_of(&self, p: Point) -> Option<usize> {
+        self.in_bounds(p).then(|| (p.y * self.width + p.x) as usize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    // All of the points in the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    // All of the cells in the grid, with their points, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    // The first point (a row at a time) whose cell matches.
+    pub fn position<F>(&self, mut predicate: F) -> Option<Point>
+    where F: FnMut(&T) -> bool
+    {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(p, _)| p)
+    }
+
+    pub fn row(&self, y: i32) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(-1, y), Point::RIGHT).map(|p| &self[p])
+    }
+
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        self.ray(Point::new(x, -1), Point::DOWN).map(|p| &self[p])
+    }
+
+    // The orthogonal neighbors of `p` that are inside the grid.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(|n| self.in_bounds(*n))
+    }
+
+    // The orthogonal and diagonal neighbors of `p` that are inside the grid.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(|n| self.in_bounds(*n))
+    }
+
+    //
+    // The points from `from` (not including it) in steps of `direction`,
+    // until the edge of the grid.  `from` may be just outside the grid,
+    // for looking in from the edge.
+    //
+    pub fn ray(&self, from: Point, direction: Point) -> impl Iterator<Item = Point> + '_ {
+        assert_ne!(direction, Point::ZERO);
+        std::iter::successors(Some(from + direction), move |p| Some(*p + direction))
+            .take_while(|p| self.in_bounds(*p))
+    }
+
+    // Draw the grid, with one character per cell.
+    pub fn render<F>(&self, mut f: F) -> String
+    where F: FnMut(&T) -> char
+    {
+        let mut result = String::with_capacity(self.cells.len() + self.height as usize);
+        for row in self.cells.chunks(self.width.max(1) as usize) {
+            result.extend(row.iter().map(&mut f));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{p:?} is outside the {}x{} grid", self.width, self.height)
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{p:?} is outside the {width}x{height} grid")
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1) as usize) {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+//
+// A grid that only stores the occupied cells.  Good for boards that are
+// mostly empty, irregularly shaped, or that grow without bound.
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid { cells: HashMap::default() }
+    }
+
+    //
+    // Parse a map with one character per cell, and one line per row.
+    // Cells where `f` returns None are left empty, so lines may be
+    // different lengths.
+    //
+    pub fn parse<F>(input: &str, mut f: F) -> Self
+    where F: FnMut(char) -> Option<T>
+    {
+        let mut grid = SparseGrid::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                if let Some(cell) = f(ch) {
+                    grid.insert(Point::new(x as i32, y as i32), cell);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    // Returns the previous contents of the cell, if any.
+    pub fn insert(&mut self, p: Point, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    // The occupied points, in no particular order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    // The occupied cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(p, cell)| (*p, cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    // The upper left and lower right occupied corners (inclusive).
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        bounding_box(self.cells.keys())
+    }
+
+    // The occupied orthogonal neighbors of `p`.
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors4().filter(|n| self.contains(*n))
+    }
+
+    // The occupied orthogonal and diagonal neighbors of `p`.
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        p.neighbors8().filter(|n| self.contains(*n))
+    }
+
+    //
+    // The points from `from` (not including it) in steps of `direction`,
+    // as long as they are occupied.
+    //
+    pub fn ray(&self, from: Point, direction: Point) -> impl Iterator<Item = Point> + '_ {
+        assert_ne!(direction, Point::ZERO);
+        std::iter::successors(Some(from + direction), move |p| Some(*p + direction))
+            .take_while(|p| self.contains(*p))
+    }
+
+    // Draw the bounding box of the occupied cells, one character per cell.
+    pub fn render<F>(&self, mut f: F) -> String
+    where F: FnMut(Option<&T>) -> char
+    {
+        let mut result = String::new();
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y ..= max.y {
+                result.extend((min.x ..= max.x).map(|x| f(self.get(Point::new(x, y)))));
+                result.push('\n');
+            }
+        }
+        result
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid { cells: iter.into_iter().collect() }
+    }
+}
+
+// Empty cells are drawn as '.'
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y ..= max.y {
+                for x in min.x ..= max.x {
+                    match self.get(Point::new(x, y)) {
+                        Some(cell) => write!(f, "{cell}")?,
+                        None => write!(f, ".")?,
+                    }
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+    fn digits() -> Grid<u32> {
+        Grid::parse(MAP, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = digits();
+        assert_eq!(grid.width(), 5);
+        assert_eq!(grid.height(), 5);
+        assert_eq!(grid[Point::new(3, 0)], 7);
+        assert_eq!(grid[Point::new(0, 2)], 6);
+        assert_eq!(grid.get(Point::new(5, 0)), None);
+        assert_eq!(grid.to_string(), MAP);
+        assert!(Grid::parse("ab\nc\n", Some).is_err());
+        let error = Grid::parse("12\n3x\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits();
+        assert_eq!(grid.neighbors4(Point::ZERO).collect::<Vec<_>>(), vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors4(Point::new(2, 2)).count(), 4);
+        assert_eq!(grid.neighbors8(Point::new(4, 4)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rays() {
+        let grid = digits();
+        let cells = |ray: Vec<Point>| ray.into_iter().map(|p| grid[p]).collect::<Vec<_>>();
+        assert_eq!(cells(grid.ray(Point::new(2, 2), Point::UP).collect()), vec![5, 3]);
+        assert_eq!(cells(grid.ray(Point::new(-1, 1), Point::RIGHT).collect()), vec![2, 5, 5, 1, 2]);
+        assert_eq!(cells(grid.ray(Point::ZERO, Point::new(1, 1)).collect()), vec![5, 3, 4, 0]);
+        assert_eq!(grid.row(4).copied().collect::<Vec<_>>(), vec![3, 5, 3, 9, 0]);
+        assert_eq!(grid.column(4).copied().collect::<Vec<_>>(), vec![3, 2, 2, 9, 0]);
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = SparseGrid::parse("  #.\n #..\n", |c| (c != ' ').then_some(c));
+        assert_eq!(grid.len(), 5);
+        assert_eq!(grid.bounds(), Some((Point::new(1, 0), Point::new(3, 1))));
+        assert_eq!(grid.ray(Point::new(3, 1), Point::LEFT).last(), Some(Point::new(1, 1)));
+        assert_eq!(grid.to_string(), ".#.\n#..\n");
+        grid.insert(Point::new(-1, -1), '@');
+        assert_eq!(grid.render(|c| c.copied().unwrap_or(' ')), "@    \n   #.\n  #..\n");
+        assert_eq!(grid.neighbors8(Point::new(2, 1)).count(), 4);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{fmt, path::{Path, PathBuf}, str::FromStr};
+use anyhow::{bail, Context};
+
+//
+// A local store of puzzle inputs, so that several people can keep
+// their inputs side by side.  Each person (or account) has a profile,
+// which is a directory of inputs:
+//
+//      inputs/
+//          alice/
+//              day01.txt
+//              day02.txt
+//          bob/
+//              day01.txt
+//
+// Inputs are identified in the answers manifest by their hash, so the
+// profile name doesn't need to match the `name` in answers.toml.
+//
+pub const DEFAULT_ROOT: &str = "inputs";
+
+// The environment variable that selects a profile when `--profile`
+// isn't given.
+pub const PROFILE_ENV: &str = "AOC_PROFILE";
+
+//
+// A profile's name.  Names become directory names, so they are checked
+// when they are parsed (from `--profile` or $AOC_PROFILE), and only a
+// checked name can be used to find a path in the store.
+//
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Profile(String);
+
+impl Profile {
+    // The profile named by $AOC_PROFILE, if it is set.
+    pub fn from_env() -> anyhow::Result<Option<Profile>> {
+        match std::env::var(PROFILE_ENV) {
+            Ok(name) => Ok(Some(name.parse().with_context(|| format!("${PROFILE_ENV}"))?)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Profile {
+    type Err = anyhow::Error;
+
+    // Letters, digits, '-', '_' or '.', but not starting with '.'
+    fn from_str(name: &str) -> anyhow::Result<Self> {
+        let valid = |ch: char| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || ch == '.';
+        if name.is_empty() || name.starts_with('.') || !name.chars().all(valid) {
+            bail!("invalid profile name {name:?}; use letters, digits, '-', '_' or '.'");
+        }
+        Ok(Profile(name.to_string()))
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+pub struct InputStore {
+    root: PathBuf,
+}
+
+impl InputStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputStore { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    // Where a profile's input for a given day lives (whether or not
+    // it exists yet).
+    pub fn path(&self, profile: &Profile, day: u8) -> PathBuf {
+        self.root.join(profile.as_str()).join(format!("day{day:02}.txt"))
+    }
+
+    // All of the profiles, sorted.  Directories whose names aren't
+    // valid profile names are left out.
+    pub fn profiles(&self) -> anyhow::Result<Vec<Profile>> {
+        if !self.root.exists() {
+            return Ok(Vec::new());
+        }
+        let mut profiles = Vec::new();
+        let entries = std::fs::read_dir(&self.root)
+            .with_context(|| format!("reading {}", self.root.display()))?;
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                if let Some(profile) = entry.file_name().to_str().and_then(|name| name.parse().ok()) {
+                    profiles.push(profile);
+                }
+            }
+        }
+        profiles.sort();
+        Ok(profiles)
+    }
+
+    // The days that a profile has inputs for, sorted.
+    pub fn days(&self, profile: &Profile) -> anyhow::Result<Vec<u8>> {
+        let dir = self.root.join(profile.as_str());
+        if !dir.is_dir() {
+            bail!("no profile named {:?} in {}", profile.as_str(), self.root.display());
+        }
+        let mut days = Vec::new();
+        for entry in std::fs::read_dir(&dir).with_context(|| format!("reading {}", dir.display()))? {
+            let name = entry?.file_name();
+            if let Some(day) = name.to_str().and_then(parse_file_name) {
+                days.push(day);
+            }
+        }
+        days.sort();
+        Ok(days)
+    }
+
+    //
+    // Store a day's input for a profile.  An existing input is only
+    // replaced if `replace` is true.  Returns the path it was saved to.
+    //
+    pub fn add(&self, profile: &Profile, day: u8, input: &str, replace: bool) -> anyhow::Result<PathBuf> {
+        let path = self.path(profile, day);
+        if path.exists() && !replace {
+            bail!("{} already exists", path.display());
+        }
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("creating {}", dir.display()))?;
+        std::fs::write(&path, input)
+            .with_context(|| format!("writing {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+impl Default for InputStore {
+    fn default() -> Self {
+        InputStore::new(DEFAULT_ROOT)
+    }
+}
+
+// "day07.txt" => Some(7)
+fn parse_file_name(name: &str) -> Option<u8> {
+    let day = name.strip_prefix("day")?.strip_suffix(".txt")?;
+    if day.len() != 2 {
+        return None;
+    }
+    match day.parse::<u8>() {
+        Ok(day @ 1..=25) => Some(day),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_file_name() {
+        assert_eq!(parse_file_name("day07.txt"), Some(7));
+        assert_eq!(parse_file_name("day25.txt"), Some(25));
+        assert_eq!(parse_file_name("day7.txt"), None);
+        assert_eq!(parse_file_name("day26.txt"), None);
+        assert_eq!(parse_file_name("notes.txt"), None);
+    }
+
+    #[test]
+    fn test_profile_names() {
+        assert_eq!("alice".parse::<Profile>().unwrap().as_str(), "alice");
+        assert!("bob-2.work_x".parse::<Profile>().is_ok());
+        for name in ["", "../eve", ".hidden", "a/b", "a b", ".."] {
+            assert!(name.parse::<Profile>().is_err(), "{name:?}");
+        }
+    }
+
+    #[test]
+    fn test_store() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-test-{}", std::process::id()));
+        let store = InputStore::new(&root);
+        assert!(store.profiles().unwrap().is_empty());
+        let [alice, bob, carol] = ["alice", "bob", "carol"].map(|name| name.parse::<Profile>().unwrap());
+
+        let path = store.add(&alice, 3, "abc\n", false).unwrap();
+        assert_eq!(path, root.join("alice").join("day03.txt"));
+        store.add(&alice, 1, "1\n", false).unwrap();
+        store.add(&bob, 1, "2\n", false).unwrap();
+        assert!(store.add(&bob, 1, "3\n", false).is_err());
+        store.add(&bob, 1, "3\n", true).unwrap();
+        std::fs::create_dir(root.join(".git")).unwrap();
+
+        assert_eq!(store.profiles().unwrap(), [alice.clone(), bob.clone()]);
+        assert_eq!(store.days(&alice).unwrap(), [1, 3]);
+        assert_eq!(std::fs::read_to_string(store.path(&bob, 1)).unwrap(), "3\n");
+        assert!(store.days(&carol).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
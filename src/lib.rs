@@ -1,6 +1,294 @@
-use std::{fmt::Debug, iter::Sum, ops::{Range, Sub}};
+use std::{fmt::Debug, io::BufRead, iter::{Peekable, Sum}, ops::{BitAnd, BitOr, BitXor, Mul, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive, Sub}};
+use anyhow::bail;
 
-#[derive(Debug)]
+pub mod answers;
+pub mod bench;
+pub mod fixtures;
+pub mod generate;
+pub mod grid;
+pub mod inputs;
+pub mod params;
+pub mod parse;
+pub mod search;
+pub mod topk;
+pub mod visualize;
+mod days;
+
+pub use generate::Rng;
+pub use params::{Param, Params};
+pub use topk::TopK;
+pub use visualize::Animation;
+
+//
+// A solver for one day's puzzle.
+//
+// Each day's module implements this for a unit struct (e.g. `Day01`),
+// and lists it in `days::ALL`.  The `aoc` binary finds solutions
+// through `solutions()` or `solution(day)`.
+//
+// Answers are returned as strings, since some puzzles have non-numeric
+// answers (crate letters, SNAFU numbers, pixels on a CRT screen).
+//
+// `params` has the values of the day's declared `params()`.
+//
+// A day with more than one algorithm for a part lists them all in
+// `strategies()`, so they can be run by name and compared.  The first
+// strategy for a part should be the one that `part1`/`part2` use.
+//
+pub trait Solution: Sync {
+    // The day of the puzzle, 1 through 25.
+    fn day(&self) -> u8;
+
+    fn part1(&self, input: &str, params: &Params) -> anyhow::Result<String>;
+
+    // Day 25 only has one part.  Days without a part 2 don't need to
+    // implement this; they override `parts()` instead.
+    fn part2(&self, _input: &str, _params: &Params) -> anyhow::Result<String> {
+        bail!("Day {} has no part 2", self.day())
+    }
+
+    // How many parts the puzzle has.
+    fn parts(&self) -> u8 {
+        2
+    }
+
+    // Numbers from the puzzle text that differ between the example and
+    // the real input.  The defaults are for the real input.
+    fn params(&self) -> &'static [Param] {
+        &[]
+    }
+
+    fn strategies(&self) -> &'static [Strategy] {
+        &[]
+    }
+
+    // Run a part's simulation, drawing each step.  Only the days that
+    // are simulations implement this.
+    fn visualize(&self, part: u8, _input: &str, _params: &Params, _animation: &mut Animation) -> anyhow::Result<()> {
+        bail!("Day {} part {part} has no visualization", self.day())
+    }
+
+    // A report about the input, beyond the answers, for days with more
+    // to say about it (`aoc analyze`).
+    fn analyze(&self, _input: &str, _params: &Params) -> anyhow::Result<String> {
+        bail!("Day {} has no analysis", self.day())
+    }
+
+    // Solve every part in one pass, reading the input as it goes, so an
+    // input too big for memory can be piped in (`aoc run --input -`).
+    // Days that can do that return the answers, and anything else they
+    // found along the way; the rest return None, without reading anything.
+    fn stream(&self, _input: &mut dyn BufRead, _params: &Params) -> Option<anyhow::Result<Streamed>> {
+        None
+    }
+
+    // A random input, `size` big in some sense that suits the day, that
+    // is a valid puzzle with these parameters.  (See `generate`.)
+    fn generate(&self, _rng: &mut Rng, _size: usize, _params: &Params) -> anyhow::Result<String> {
+        bail!("Day {} has no input generator", self.day())
+    }
+
+    fn solve(&self, part: u8, input: &str, params: &Params) -> anyhow::Result<String> {
+        match part {
+            1 => self.part1(input, params),
+            2 if self.parts() >= 2 => self.part2(input, params),
+            _ => bail!("Day {} has no part {}", self.day(), part)
+        }
+    }
+
+    // The strategies for one part, in order (default first).
+    fn strategies_for(&self, part: u8) -> Vec<&'static Strategy> {
+        self.strategies().iter().filter(|strategy| strategy.part == part).collect()
+    }
+
+    fn solve_with(&self, part: u8, strategy: &str, input: &str, params: &Params) -> anyhow::Result<String> {
+        let strategies = self.strategies_for(part);
+        match strategies.iter().find(|s| s.name == strategy) {
+            Some(s) => (s.solve)(input, params),
+            None if strategies.is_empty() => {
+                bail!("Day {} part {part} has no strategies; got {strategy:?}", self.day())
+            }
+            None => {
+                let names = strategies.iter().map(|s| s.name).collect::<Vec<_>>();
+                bail!("Day {} part {part} has no strategy {strategy:?}; expected one of: {}", self.day(), names.join(", "))
+            }
+        }
+    }
+}
+
+//
+// One way of solving a part.  `slow` strategies are impractical for
+// the real input, so they are only run when asked for by name.
+//
+#[derive(Clone, Copy)]
+pub struct Strategy {
+    pub part: u8,
+    pub name: &'static str,
+    pub help: &'static str,
+    pub slow: bool,
+    pub solve: fn(&str, &Params) -> anyhow::Result<String>,
+}
+
+//
+// What `stream` found: the answers, in order by part, and a report
+// about the input (like `analyze` gives), shown after the answers.
+//
+pub struct Streamed {
+    pub answers: Vec<String>,
+    pub report: Option<String>,
+}
+
+// All of the solutions, in order by day.
+pub fn solutions() -> &'static [&'static dyn Solution] {
+    &days::ALL
+}
+
+// The solution for a given day, if there is one.
+pub fn solution(day: u8) -> Option<&'static dyn Solution> {
+    solutions().iter().copied().find(|solution| solution.day() == day)
+}
+
+// The default location of a day's puzzle input, relative to the
+// top of the repository.
+pub fn default_input_path(day: u8) -> String {
+    format!("src/days/day{day:02}/input.txt")
+}
+
+//
+// Types with a "next" value, so that a single value `x` can be treated
+// as the range `x..x+1`, and an inclusive range `a..=b` as `a..b+1`.
+// (Like the unstable `std::iter::Step`.)  The largest value has no
+// successor.
+//
+pub trait Step: Copy + Ord {
+    fn successor(self) -> Option<Self>;
+}
+
+macro_rules! step_integer {
+    ($($t:ty)*) => {
+        $(
+            impl Step for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+            }
+        )*
+    };
+}
+
+step_integer!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+impl Step for char {
+    fn successor(self) -> Option<Self> {
+        // Skip over the surrogates, which aren't valid chars
+        match self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            _ => char::from_u32(self as u32 + 1)
+        }
+    }
+}
+
+//
+// After the largest finite value comes `PosInfinity`, so in a
+// `RangeSet<Extended<T>>`, an inclusive range can end at `T::MAX`.
+// Nothing comes right after `NegInfinity` or `PosInfinity`.
+//
+impl<T: Step> Step for Extended<T> {
+    fn successor(self) -> Option<Self> {
+        match self {
+            Extended::Finite(value) => Some(value.successor().map_or(Extended::PosInfinity, Extended::Finite)),
+            _ => None
+        }
+    }
+}
+
+//
+// The end of a half-open range that stops after `last`.  If nothing
+// comes after `last` (like `u8::MAX` or `char::MAX`), there is no such
+// range of `T`, so those values need a `RangeSet<Extended<T>>`.
+//
+fn end_after<T: Step + Debug>(last: T) -> T {
+    last.successor().unwrap_or_else(|| {
+        panic!("no half-open range ends after {last:?}; use a RangeSet<Extended<_>> for ranges up to the largest value")
+    })
+}
+
+//
+// Things that can be inserted into or removed from a `RangeSet<T>`
+// (or used to query it): a `Range<T>`, a `RangeInclusive<T>`, or a
+// single `T`.  A `RangeSet<Extended<T>>` also accepts unbounded ranges,
+// like `..x`, `x..` and `..`.
+//
+pub trait IntoRange<T> {
+    fn into_range(self) -> Range<T>;
+}
+
+impl<T> IntoRange<T> for Range<T> {
+    fn into_range(self) -> Range<T> {
+        self
+    }
+}
+
+impl<T: Step + Debug> IntoRange<T> for RangeInclusive<T> {
+    fn into_range(self) -> Range<T> {
+        let (start, end) = self.into_inner();
+        start .. end_after(end)
+    }
+}
+
+impl<T: Step + Debug> IntoRange<T> for T {
+    fn into_range(self) -> Range<T> {
+        self .. end_after(self)
+    }
+}
+
+//
+// A value of `T`, or one of the infinities beyond all of them.  This is
+// what lets a `RangeSet` hold ranges with unbounded ends.  The derived
+// order puts `NegInfinity` first and `PosInfinity` last, with the
+// `Finite` values in their usual order between them.
+//
+// Unbounded ranges of `T` convert to ranges of `Extended<T>`.  Bounded
+// ranges don't (so that `RangeSet::new()` followed by `insert(a..b)`
+// can still infer `RangeSet<T>`); write them as `Finite(a)..Finite(b)`.
+//
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Extended<T> {
+    NegInfinity,
+    Finite(T),
+    PosInfinity,
+}
+
+impl<T> IntoRange<Extended<T>> for RangeTo<T> {
+    fn into_range(self) -> Range<Extended<T>> {
+        Extended::NegInfinity .. Extended::Finite(self.end)
+    }
+}
+
+impl<T: Step> IntoRange<Extended<T>> for RangeToInclusive<T> {
+    fn into_range(self) -> Range<Extended<T>> {
+        Extended::NegInfinity .. Extended::Finite(self.end).successor().unwrap()
+    }
+}
+
+impl<T> IntoRange<Extended<T>> for RangeFrom<T> {
+    fn into_range(self) -> Range<Extended<T>> {
+        Extended::Finite(self.start) .. Extended::PosInfinity
+    }
+}
+
+impl<T> IntoRange<Extended<T>> for RangeFull {
+    fn into_range(self) -> Range<Extended<T>> {
+        Extended::NegInfinity .. Extended::PosInfinity
+    }
+}
+
+//
+// A set of values, stored as sorted, disjoint ranges.  All it needs from
+// `T` is a total order, so it works for integers, chars, timestamps, or
+// `Extended<T>` (for unbounded ranges).  Only `len()` needs arithmetic.
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RangeSet<T>
 {
     // All of the ranges are non-overlapping.
@@ -10,87 +298,75 @@ pub struct RangeSet<T>
 }
 
 impl<T> RangeSet<T>
-where T: Copy + Clone + PartialOrd + Ord + Sum<T> + Sub + Sum<<T as Sub>::Output>
+where T: Ord + Copy
 {
     pub fn new() -> Self {
         Self { ranges: Vec::new() }
     }
 
-    pub fn insert(&mut self, range: Range<T>) {
-        //TODO: This could probably be handled by Iterator::scan
-
+    //
+    // Insert and remove only touch the ranges that overlap (or, for insert,
+    // are adjacent to) the given range.  Those are a contiguous run in the
+    // sorted vector, which we find with a binary search for each end, then
+    // replace with `splice`.  The cost is O(log n) to find the run, plus
+    // moving the ranges after it.
+    //
+    pub fn insert(&mut self, range: impl IntoRange<T>) {
+        let range = range.into_range();
         if range.is_empty() {
             return;
         }
 
-        // First, insert the new range in sorted order.
-        self.ranges.push(range);
-        self.ranges.sort_unstable_by_key(|r| r.start);
+        // Ranges in lo..hi overlap or touch `range`
+        let lo = self.ranges.partition_point(|r| r.end < range.start);
+        let hi = self.ranges.partition_point(|r| r.start <= range.end);
 
-        // Now collapse any overlapping or adjacent ranges.
-        let mut head = 0;   // Last index of fixed-up ranges
-        let mut tail = 1;   // Index of next range to consider
-        while tail < self.ranges.len() {
-            if self.ranges[head].end < self.ranges[tail].start {
-                // Ranges are disjoint
-                if head+1 < tail {
-                    self.ranges[head+1] = self.ranges[tail].clone();
-                }
-                head += 1;
-                tail += 1;
-            } else {
-                // Combine these two ranges
-                self.ranges[head].end = self.ranges[head].end.max(self.ranges[tail].end);
-                tail += 1;
-            }
+        let mut merged = range;
+        if lo < hi {
+            merged.start = merged.start.min(self.ranges[lo].start);
+            merged.end = merged.end.max(self.ranges[hi-1].end);
         }
-
-        self.ranges.truncate(head+1);
+        self.ranges.splice(lo..hi, [merged]);
     }
 
-    pub fn remove(&mut self, removed: Range<T>) {
-        // We could just adjust start/end of existing ranges, and remove
-        // ranges that have become empty.  The one remaining case would
-        // be that the input `range` is in the middle of an existing range,
-        // which then needs to be replaced with two disjoint ranges.
-        
+    pub fn remove(&mut self, removed: impl IntoRange<T>) {
+        let removed = removed.into_range();
         if removed.is_empty() {
             return;
         }
 
-        self.ranges = self.ranges.iter().flat_map(|r| {
-            if r.end <= removed.start {
-                return vec![r.clone()];
-            }
-            if r.start >= removed.end {
-                return vec![r.clone()];
-            }
+        // Ranges in lo..hi overlap `removed`
+        let lo = self.ranges.partition_point(|r| r.end <= removed.start);
+        let hi = self.ranges.partition_point(|r| r.start < removed.end);
+        if lo == hi {
+            return;
+        }
 
-            // If we get this far, there is some overlap
+        // Keep whatever sticks out past either end of `removed`.
+        let head = self.ranges[lo].start .. removed.start;
+        let tail = removed.end .. self.ranges[hi-1].end;
+        let pieces = [head, tail].into_iter().filter(|r| !r.is_empty());
+        self.ranges.splice(lo..hi, pieces);
+    }
 
-            // See if `removed` completely covers `r`
-            if removed.start <= r.start && removed.end >= r.end {
-                return vec![];
-            }
-            // See if we need to split `r` into two ranges
-            if r.start < removed.start && removed.end < r.end {
-                return vec![r.start .. removed.start, removed.end .. r.end];
-            }
+    // The ranges in the set, in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Range<T>> {
+        self.ranges.iter()
+    }
 
-            // If we get this far, either the start or the end of `r`
-            // (but not both) needs to be adjusted.
-            let mut res = r.clone();
-            if removed.start <= res.start {
-                res.start = removed.end;
-            } else {
-                res.end = removed.start;
-            }
-            assert!(!res.is_empty());
-            vec![res]
-        }).collect();
+    // The individual values in the set, in order.
+    pub fn values(&self) -> impl Iterator<Item=T> + '_
+    where T: Step
+    {
+        self.ranges.iter().flat_map(|r| {
+            let end = r.end;
+            std::iter::successors(Some(r.start), |x| x.successor())
+                .take_while(move |x| *x < end)
+        })
     }
 
-    pub fn intersect(&mut self, keep: Range<T>) {
+    pub fn intersect(&mut self, keep: impl IntoRange<T>) {
+        let keep = keep.into_range();
         self.ranges = self.ranges.iter().filter_map(|r| {
             if r.end <= keep.start {
                 return None;
@@ -114,9 +390,148 @@ where T: Copy + Clone + PartialOrd + Ord + Sum<T> + Sub + Sum<<T as Sub>::Output
         }).collect();
     }
 
-    pub fn len(&self) -> T {
-        assert!(self.is_consistent());
-        self.ranges.iter().map(|r| r.end - r.start).sum()
+    //
+    // Membership queries.  The ranges are sorted and disjoint, so the
+    // ranges' ends are sorted, too.  A binary search for the first range
+    // that ends after a value finds the only range that could contain it.
+    //
+    fn first_ending_after(&self, value: T) -> usize {
+        self.ranges.partition_point(|r| r.end <= value)
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.first_ending_after(*value);
+        self.ranges.get(index).is_some_and(|r| r.start <= *value)
+    }
+
+    // Is every value in `range` in the set?  (An empty range is.)
+    pub fn contains_range(&self, range: impl IntoRange<T>) -> bool {
+        let range = range.into_range();
+        if range.is_empty() {
+            return true;
+        }
+        let index = self.first_ending_after(range.start);
+        self.ranges.get(index).is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    // Is any value in `range` in the set?
+    pub fn overlaps(&self, range: impl IntoRange<T>) -> bool {
+        let range = range.into_range();
+        if range.is_empty() {
+            return false;
+        }
+        let index = self.first_ending_after(range.start);
+        self.ranges.get(index).is_some_and(|r| r.start < range.end)
+    }
+
+    // The first run of values in `within` that are not in the set.
+    pub fn first_gap_in(&self, within: impl IntoRange<T>) -> Option<Range<T>> {
+        let within = within.into_range();
+        if within.is_empty() {
+            return None;
+        }
+        let mut index = self.first_ending_after(within.start);
+        let mut start = within.start;
+        if let Some(r) = self.ranges.get(index) {
+            if r.start <= start {
+                start = r.end;
+                index += 1;
+            }
+        }
+        let end = match self.ranges.get(index) {
+            Some(r) => r.start.min(within.end),
+            None => within.end
+        };
+        (start < end).then_some(start..end)
+    }
+
+    // All of the values in `within` that are not in the set.
+    pub fn complement_within(&self, within: impl IntoRange<T>) -> Self {
+        let within = within.into_range();
+        let mut ranges = Vec::new();
+        let mut start = within.start;
+        for r in &self.ranges[self.first_ending_after(within.start)..] {
+            if r.start >= within.end {
+                break;
+            }
+            if start < r.start {
+                ranges.push(start..r.start);
+            }
+            start = r.end;
+        }
+        if start < within.end {
+            ranges.push(start..within.end);
+        }
+        Self { ranges }
+    }
+
+    // The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        self.merge(other, |a, b| a || b)
+    }
+
+    // The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.merge(other, |a, b| a && b)
+    }
+
+    // The values in `self`, but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.merge(other, |a, b| a && !b)
+    }
+
+    // The values in exactly one of the sets.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.merge(other, |a, b| a != b)
+    }
+
+    //
+    // Combine two sets by sweeping over the starts and ends of their
+    // ranges in sorted order.  Membership in each set only changes at
+    // those boundaries, so `keep` only needs to be evaluated there.
+    // Both sets are consistent, so this is a single linear pass.
+    //
+    // All boundaries at the same value are handled together, so the
+    // result never has empty or adjacent ranges.
+    //
+    fn merge<F>(&self, other: &Self, keep: F) -> Self
+    where F: Fn(bool, bool) -> bool
+    {
+        let mut a = self.ranges.iter().flat_map(|r| [r.start, r.end]).peekable();
+        let mut b = other.ranges.iter().flat_map(|r| [r.start, r.end]).peekable();
+        let mut in_a = false;
+        let mut in_b = false;
+        let mut inside = false;
+        let mut ranges: Vec<Range<T>> = Vec::new();
+
+        loop {
+            let x = match (a.peek(), b.peek()) {
+                (None, None) => break,
+                (Some(&x), None) | (None, Some(&x)) => x,
+                (Some(&x), Some(&y)) => x.min(y),
+            };
+            if a.next_if_eq(&x).is_some() {
+                in_a = !in_a;
+            }
+            if b.next_if_eq(&x).is_some() {
+                in_b = !in_b;
+            }
+            let now = keep(in_a, in_b);
+            if now != inside {
+                if now {
+                    ranges.push(x..x);
+                } else {
+                    ranges.last_mut().unwrap().end = x;
+                }
+                inside = now;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
     }
 
     pub fn is_consistent(&self) -> bool {
@@ -143,14 +558,379 @@ where T: Copy + Clone + PartialOrd + Ord + Sum<T> + Sub + Sum<<T as Sub>::Output
     }
 }
 
+//
+// The number of values in the set needs arithmetic on `T`, which
+// not every ordered type has (like `char`, or `Extended<T>`).
+//
+impl<T> RangeSet<T>
+where T: Ord + Copy + Sub + Sum<<T as Sub>::Output>
+{
+    pub fn len(&self) -> T {
+        assert!(self.is_consistent());
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+}
+
 impl<T> Default for RangeSet<T>
-where T: Copy + Clone + PartialOrd + Ord + Sum<T> + Sub + Sum<<T as Sub>::Output>
+where T: Ord + Copy
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//
+// A set can be built from (or extended by) anything that can be inserted:
+// ranges, inclusive ranges, or individual values.  Adjacent values merge
+// into a single range, so scattered values collapse into runs.
+//
+impl<T, R> FromIterator<R> for RangeSet<T>
+where T: Ord + Copy,
+      R: IntoRange<T>
+{
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T, R> Extend<R> for RangeSet<T>
+where T: Ord + Copy,
+      R: IntoRange<T>
+{
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T> IntoIterator for RangeSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RangeSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = std::slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+//
+// Operators for the set algebra, like `std::collections::BTreeSet`:
+// `a | b` is union, `a & b` is intersection, `a - b` is difference,
+// and `a ^ b` is symmetric difference.  They work on references
+// (leaving the operands alone) or on owned sets.
+//
+macro_rules! range_set_operator {
+    ($trait:ident, $method:ident, $function:ident) => {
+        impl<T> $trait<&RangeSet<T>> for &RangeSet<T>
+        where T: Ord + Copy
+        {
+            type Output = RangeSet<T>;
+
+            fn $method(self, other: &RangeSet<T>) -> RangeSet<T> {
+                self.$function(other)
+            }
+        }
+
+        impl<T> $trait for RangeSet<T>
+        where T: Ord + Copy
+        {
+            type Output = RangeSet<T>;
+
+            fn $method(self, other: RangeSet<T>) -> RangeSet<T> {
+                self.$function(&other)
+            }
+        }
+    };
+}
+
+range_set_operator!(BitOr, bitor, union);
+range_set_operator!(BitAnd, bitand, intersection);
+range_set_operator!(Sub, sub, difference);
+range_set_operator!(BitXor, bitxor, symmetric_difference);
+
+//
+// A set of cells in N dimensions, stored as a union of axis-aligned boxes.
+// A box is given by a range along each axis (like `[x0..x1, y0..y1]`).
+//
+// Internally, the set is cut into slabs along the first axis.  Each slab
+// is a range of the first axis where the cross-section (in the remaining
+// axes) is the same.  The cross-section is another set of slabs, down to
+// the last axis, which is just a `RangeSet`.  Like `RangeSet`, the slabs
+// are sorted, disjoint, non-empty, and adjacent slabs never have the same
+// cross-section; so equal sets have equal representations.
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoxSet<T, const N: usize> {
+    region: Region<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Region<T> {
+    // The last axis
+    Line(RangeSet<T>),
+    // Any other axis: each slab's range, and its cross-section
+    Slabs(Vec<(Range<T>, Region<T>)>),
+}
+
+impl<T: Ord + Copy> Region<T> {
+    fn empty(dimensions: usize) -> Self {
+        if dimensions == 1 {
+            Region::Line(RangeSet::new())
+        } else {
+            Region::Slabs(Vec::new())
+        }
+    }
+
+    fn from_box(bounds: &[Range<T>]) -> Self {
+        match bounds {
+            [range] => {
+                let mut line = RangeSet::new();
+                line.insert(range.clone());
+                Region::Line(line)
+            }
+            [range, rest @ ..] => {
+                let cross_section = Region::from_box(rest);
+                if range.is_empty() || cross_section.is_empty() {
+                    Region::Slabs(Vec::new())
+                } else {
+                    Region::Slabs(vec![(range.clone(), cross_section)])
+                }
+            }
+            [] => unreachable!("BoxSet needs at least one dimension")
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Region::Line(line) => line.is_empty(),
+            Region::Slabs(slabs) => slabs.is_empty(),
+        }
+    }
+
+    //
+    // Combine two regions (with the same number of dimensions), like
+    // `RangeSet::merge`.  The boundaries of both regions' slabs cut the
+    // first axis into pieces.  Within each piece, the cross-sections
+    // are combined recursively.
+    //
+    fn merge(&self, other: &Self, keep: &dyn Fn(bool, bool) -> bool) -> Self {
+        let (a, b) = match (self, other) {
+            (Region::Line(a), Region::Line(b)) => return Region::Line(a.merge(b, keep)),
+            (Region::Slabs(a), Region::Slabs(b)) => (a, b),
+            _ => panic!("BoxSet regions have different dimensions")
+        };
+
+        let mut cuts: Vec<T> = a.iter().chain(b.iter())
+            .flat_map(|(r, _)| [r.start, r.end])
+            .collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut slabs: Vec<(Range<T>, Region<T>)> = Vec::new();
+        let mut a = a.iter().peekable();
+        let mut b = b.iter().peekable();
+        for piece in cuts.windows(2) {
+            let (start, end) = (piece[0], piece[1]);
+            let cross_section = match (slab_at(&mut a, start), slab_at(&mut b, start)) {
+                (Some(x), Some(y)) => x.merge(y, keep),
+                (Some(x), None) if keep(true, false) => x.clone(),
+                (None, Some(y)) if keep(false, true) => y.clone(),
+                _ => continue
+            };
+            if cross_section.is_empty() {
+                continue;
+            }
+            match slabs.last_mut() {
+                Some((range, previous)) if range.end == start && *previous == cross_section => {
+                    range.end = end;
+                }
+                _ => slabs.push((start..end, cross_section))
+            }
+        }
+
+        Region::Slabs(slabs)
+    }
+
+    fn contains(&self, point: &[T]) -> bool {
+        match self {
+            Region::Line(line) => line.contains(&point[0]),
+            Region::Slabs(slabs) => {
+                let index = slabs.partition_point(|(r, _)| r.end <= point[0]);
+                slabs.get(index).is_some_and(|(r, cross_section)| {
+                    r.start <= point[0] && cross_section.contains(&point[1..])
+                })
+            }
+        }
+    }
+
+    // Call `f` with the bounds of each box in the region.
+    fn for_each_box(&self, prefix: &mut Vec<Range<T>>, f: &mut dyn FnMut(&[Range<T>])) {
+        match self {
+            Region::Line(line) => {
+                for range in line {
+                    prefix.push(range.clone());
+                    f(prefix);
+                    prefix.pop();
+                }
+            }
+            Region::Slabs(slabs) => {
+                for (range, cross_section) in slabs {
+                    prefix.push(range.clone());
+                    cross_section.for_each_box(prefix, f);
+                    prefix.pop();
+                }
+            }
+        }
+    }
+}
+
+//
+// The cross-section of the slab containing `x`, if any.  The slabs are
+// visited in increasing order of `x`, so skip past any that end before it.
+//
+fn slab_at<'a, T: Ord + Copy>(slabs: &mut Peekable<std::slice::Iter<'a, (Range<T>, Region<T>)>>, x: T) -> Option<&'a Region<T>> {
+    while slabs.next_if(|(r, _)| r.end <= x).is_some() {}
+    slabs.peek().filter(|(r, _)| r.start <= x).map(|(_, region)| region)
+}
+
+impl<T, const N: usize> BoxSet<T, N>
+where T: Ord + Copy
+{
+    pub fn new() -> Self {
+        assert!(N > 0, "BoxSet needs at least one dimension");
+        Self { region: Region::empty(N) }
+    }
+
+    // A set containing just the given box.
+    pub fn from_box<R: IntoRange<T>>(bounds: [R; N]) -> Self {
+        assert!(N > 0, "BoxSet needs at least one dimension");
+        let bounds = bounds.map(IntoRange::into_range);
+        Self { region: Region::from_box(&bounds) }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    pub fn insert<R: IntoRange<T>>(&mut self, bounds: [R; N]) {
+        *self = self.union(&Self::from_box(bounds));
+    }
+
+    pub fn remove<R: IntoRange<T>>(&mut self, bounds: [R; N]) {
+        *self = self.difference(&Self::from_box(bounds));
+    }
+
+    // Remove everything outside of the given box.
+    pub fn intersect<R: IntoRange<T>>(&mut self, bounds: [R; N]) {
+        *self = self.intersection(&Self::from_box(bounds));
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.region.contains(point)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self { region: self.region.merge(&other.region, &|a, b| a || b) }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self { region: self.region.merge(&other.region, &|a, b| a && b) }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self { region: self.region.merge(&other.region, &|a, b| a && !b) }
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        Self { region: self.region.merge(&other.region, &|a, b| a != b) }
+    }
+
+    // Disjoint boxes whose union is the set.
+    pub fn boxes(&self) -> Vec<[Range<T>; N]> {
+        let mut boxes = Vec::new();
+        self.region.for_each_box(&mut Vec::with_capacity(N), &mut |bounds| {
+            boxes.push(std::array::from_fn(|axis| bounds[axis].clone()));
+        });
+        boxes
+    }
+
+    // Every cell within `bounds` that is not in the set.
+    pub fn uncovered_cells<R: IntoRange<T>>(&self, bounds: [R; N]) -> Vec<[T; N]>
+    where T: Step
+    {
+        let mut cells = Vec::new();
+        for bounds in Self::from_box(bounds).difference(self).boxes() {
+            // Count through the cells like an odometer, last axis fastest
+            let mut cell = bounds.clone().map(|r| r.start);
+            'cells: loop {
+                cells.push(cell);
+                for axis in (0..N).rev() {
+                    if let Some(next) = cell[axis].successor().filter(|next| *next < bounds[axis].end) {
+                        cell[axis] = next;
+                        continue 'cells;
+                    }
+                    cell[axis] = bounds[axis].start;
+                }
+                break;
+            }
+        }
+        cells
+    }
+}
+
+impl<T, const N: usize> BoxSet<T, N>
+where T: Ord + Copy + Sub<Output = T> + Mul<Output = T> + Sum
+{
+    // The number of cells in the set.
+    pub fn volume(&self) -> T {
+        self.boxes().iter().map(|bounds| {
+            bounds.iter().map(|r| r.end - r.start).reduce(|a, b| a * b).unwrap()
+        }).sum()
+    }
+}
+
+impl<T, const N: usize> Default for BoxSet<T, N>
+where T: Ord + Copy
 {
     fn default() -> Self {
         Self::new()
     }
 }
 
+//
+// A set can be built from many boxes at once.  Inserting them one at a
+// time would merge each box into an ever bigger set; instead, the boxes
+// are merged in pairs, then the pairs in pairs, and so on, so each box
+// takes part in O(log n) merges of sets that are mostly small.
+//
+impl<T, R, const N: usize> FromIterator<[R; N]> for BoxSet<T, N>
+where T: Ord + Copy,
+      R: IntoRange<T>
+{
+    fn from_iter<I: IntoIterator<Item = [R; N]>>(iter: I) -> Self {
+        let mut sets = iter.into_iter().map(Self::from_box).collect::<Vec<_>>();
+        while sets.len() > 1 {
+            sets = sets.chunks(2).map(|pair| match pair {
+                [a, b] => a.union(b),
+                [a] => a.clone(),
+                _ => unreachable!()
+            }).collect();
+        }
+        sets.pop().unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -240,4 +1020,301 @@ mod tests {
         set.remove(16..19);
         assert_eq!(set.ranges, vec![12..16, 19..25]);
     }
+
+    #[test]
+    fn test_remove_spanning() {
+        let mut set = RangeSet::new();
+        set.insert(1..5);
+        set.insert(8..12);
+        set.insert(15..20);
+        set.insert(25..30);
+        set.remove(3..17);
+        assert_eq!(set.ranges, vec![1..3, 17..20, 25..30]);
+        set.remove(0..40);
+        assert_eq!(set.ranges, vec![]);
+    }
+
+    #[test]
+    fn test_insert_between() {
+        let mut set = RangeSet::new();
+        set.insert(20..25);
+        set.insert(1..5);
+        set.insert(10..12);
+        set.insert(30..31);
+        assert_eq!(set.ranges, vec![1..5, 10..12, 20..25, 30..31]);
+        set.insert(12..20);
+        assert!(set.is_consistent());
+        assert_eq!(set.ranges, vec![1..5, 10..25, 30..31]);
+    }
+
+    fn set_of(ranges: &[Range<i32>]) -> RangeSet<i32> {
+        let mut set = RangeSet::new();
+        for range in ranges {
+            set.insert(range.clone());
+        }
+        set
+    }
+
+    #[test]
+    fn test_union() {
+        let a = set_of(&[1..5, 10..15, 20..25]);
+        let b = set_of(&[3..7, 15..18, 30..32]);
+        let result = &a | &b;
+        assert!(result.is_consistent());
+        assert_eq!(result.ranges, vec![1..7, 10..18, 20..25, 30..32]);
+        assert_eq!(a.union(&RangeSet::new()), a);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = set_of(&[1..5, 10..15, 20..25]);
+        let b = set_of(&[3..12, 14..22]);
+        let result = &a & &b;
+        assert!(result.is_consistent());
+        assert_eq!(result.ranges, vec![3..5, 10..12, 14..15, 20..22]);
+        assert_eq!(set_of(&[1..5, 7..8]) & set_of(&[5..7, 8..9]), RangeSet::new());
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = set_of(&[1..10, 20..30]);
+        let b = set_of(&[3..5, 8..22, 29..40]);
+        let result = &a - &b;
+        assert!(result.is_consistent());
+        assert_eq!(result.ranges, vec![1..3, 5..8, 22..29]);
+        assert_eq!((&b - &b).ranges, vec![]);
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let a = set_of(&[1..10, 20..30]);
+        let b = set_of(&[5..20, 25..30]);
+        let result = a ^ b;
+        assert!(result.is_consistent());
+        assert_eq!(result.ranges, vec![1..5, 10..25]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = set_of(&[1..5, 10..15]);
+        assert!(!set.contains(&0));
+        assert!(set.contains(&1));
+        assert!(set.contains(&4));
+        assert!(!set.contains(&5));
+        assert!(set.contains(&12));
+        assert!(!set.contains(&15));
+        assert!(!RangeSet::new().contains(&3));
+    }
+
+    #[test]
+    fn test_contains_range() {
+        let set = set_of(&[1..5, 10..15]);
+        assert!(set.contains_range(1..5));
+        assert!(set.contains_range(11..13));
+        assert!(!set.contains_range(3..11));
+        assert!(!set.contains_range(0..2));
+        assert!(!set.contains_range(14..16));
+        assert!(set.contains_range(7..7));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let set = set_of(&[1..5, 10..15]);
+        assert!(set.overlaps(4..10));
+        assert!(set.overlaps(0..2));
+        assert!(set.overlaps(14..20));
+        assert!(!set.overlaps(5..10));
+        assert!(!set.overlaps(15..20));
+        assert!(!set.overlaps(3..3));
+    }
+
+    #[test]
+    fn test_first_gap_in() {
+        let set = set_of(&[1..5, 10..15]);
+        assert_eq!(set.first_gap_in(0..20), Some(0..1));
+        assert_eq!(set.first_gap_in(1..20), Some(5..10));
+        assert_eq!(set.first_gap_in(7..20), Some(7..10));
+        assert_eq!(set.first_gap_in(12..20), Some(15..20));
+        assert_eq!(set.first_gap_in(12..15), None);
+        assert_eq!(set.first_gap_in(2..8), Some(5..8));
+        assert_eq!(RangeSet::new().first_gap_in(3..6), Some(3..6));
+    }
+
+    #[test]
+    fn test_complement_within() {
+        let set = set_of(&[1..5, 10..15, 20..25]);
+        assert_eq!(set.complement_within(0..30).ranges, vec![0..1, 5..10, 15..20, 25..30]);
+        assert_eq!(set.complement_within(3..22).ranges, vec![5..10, 15..20]);
+        assert_eq!(set.complement_within(11..14).ranges, vec![]);
+        assert_eq!(set.complement_within(6..8).ranges, vec![6..8]);
+    }
+
+    #[test]
+    fn test_collect_ranges() {
+        let set: RangeSet<i32> = vec![10..15, 1..5, 4..8, 15..16].into_iter().collect();
+        assert_eq!(set.ranges, vec![1..8, 10..16]);
+
+        let set: RangeSet<i32> = [1..=4, 5..=5, 9..=12].into_iter().collect();
+        assert_eq!(set.ranges, vec![1..6, 9..13]);
+    }
+
+    #[test]
+    fn test_collect_values() {
+        let set: RangeSet<u8> = [7, 3, 4, 12, 5, 8, 11, 4].into_iter().collect();
+        assert!(set.is_consistent());
+        assert_eq!(set.ranges, vec![3..6, 7..9, 11..13]);
+        assert_eq!(set.values().collect::<Vec<_>>(), vec![3, 4, 5, 7, 8, 11, 12]);
+    }
+
+    #[test]
+    fn test_extend_and_iterate() {
+        let mut set = RangeSet::new();
+        set.insert(1..3);
+        set.extend([5, 3, 4]);
+        set.extend([10..=11]);
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), vec![1..6, 10..12]);
+        assert_eq!((&set).into_iter().count(), 2);
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1..6, 10..12]);
+    }
+
+    #[test]
+    fn test_inclusive_queries() {
+        let set = set_of(&[1..5, 10..15]);
+        assert!(set.contains_range(1..=4));
+        assert!(!set.contains_range(1..=5));
+        assert!(set.overlaps(5..=10));
+        assert_eq!(set.first_gap_in(0..=20), Some(0..1));
+        assert_eq!(set.complement_within(3..=12).ranges, vec![5..10]);
+    }
+
+    #[test]
+    fn test_unbounded() {
+        use Extended::*;
+
+        let mut set = RangeSet::new();
+        set.insert(..0);
+        set.insert(10..);
+        set.insert(Finite(3)..Finite(5));
+        assert!(set.is_consistent());
+        assert_eq!(set.ranges, vec![NegInfinity..Finite(0), Finite(3)..Finite(5), Finite(10)..PosInfinity]);
+        assert!(set.contains(&Finite(-1_000_000)));
+        assert!(set.contains(&Finite(i64::MAX)));
+        assert!(!set.contains(&Finite(7)));
+        assert_eq!(set.first_gap_in(..), Some(Finite(0)..Finite(3)));
+
+        set.remove(..=3);
+        set.remove(20..);
+        assert_eq!(set.ranges, vec![Finite(4)..Finite(5), Finite(10)..Finite(20)]);
+
+        let everything: RangeSet<Extended<i64>> = [..].into_iter().collect();
+        let complement = &everything - &set;
+        assert_eq!(complement.ranges, vec![NegInfinity..Finite(4), Finite(5)..Finite(10), Finite(20)..PosInfinity]);
+    }
+
+    #[test]
+    fn test_chars() {
+        let set: RangeSet<char> = "hello, world".chars().collect();
+        assert_eq!(set.ranges, vec![' '..'!', ','..'-', 'd'..'f', 'h'..'i', 'l'..'m', 'o'..'p', 'r'..'s', 'w'..'x']);
+        let letters: RangeSet<char> = ['a'..='z'].into_iter().collect();
+        assert_eq!((&set & &letters).values().collect::<String>(), "dehlorw");
+        assert_eq!('\u{D7FF}'.successor(), Some('\u{E000}'));
+        assert_eq!(char::MAX.successor(), None);
+    }
+
+    #[test]
+    fn test_up_to_max() {
+        use Extended::*;
+
+        let mut set = RangeSet::new();
+        set.insert(Finite(250u8)..=Finite(u8::MAX));
+        assert_eq!(set.ranges, vec![Finite(250)..PosInfinity]);
+        set.insert(..=10u8);
+        assert_eq!(set.ranges, vec![NegInfinity..Finite(11), Finite(250)..PosInfinity]);
+        set.remove(Finite(u8::MAX));
+        assert_eq!(set.ranges, vec![NegInfinity..Finite(11), Finite(250)..Finite(255)]);
+
+        let mut chars = RangeSet::new();
+        chars.insert(Finite('\u{10FF00}')..=Finite(char::MAX));
+        assert!(chars.contains(&Finite(char::MAX)));
+        assert_eq!(chars.ranges, vec![Finite('\u{10FF00}')..PosInfinity]);
+
+        // Counting up to the largest value stops there
+        let top: RangeSet<Extended<u8>> = [Finite(253)..PosInfinity].into_iter().collect();
+        assert_eq!(top.values().collect::<Vec<_>>(), vec![Finite(253), Finite(254), Finite(255)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "no half-open range ends after 255")]
+    fn test_inclusive_max_needs_extended() {
+        let mut set = RangeSet::new();
+        set.insert(250u8..=255);
+    }
+
+    #[test]
+    #[should_panic(expected = "no half-open range ends after '\\u{10ffff}'")]
+    fn test_char_max_needs_extended() {
+        let mut set = RangeSet::new();
+        set.insert(char::MAX);
+    }
+
+    #[test]
+    fn test_box_set_2d() {
+        let mut set = BoxSet::new();
+        set.insert([0..4, 0..4]);
+        set.insert([2..6, 2..6]);
+        assert_eq!(set.volume(), 28);
+        assert!(set.contains(&[1, 1]));
+        assert!(set.contains(&[5, 5]));
+        assert!(!set.contains(&[1, 5]));
+        assert!(!set.contains(&[5, 1]));
+
+        set.remove([1..=4, 1..=4]);
+        assert_eq!(set.volume(), 14);
+        assert!(!set.contains(&[2, 2]));
+
+        set.intersect([0..3, 0..10]);
+        assert_eq!(set.volume(), 7);
+        assert_eq!(set.boxes(), vec![[0..1, 0..4], [1..2, 0..1], [2..3, 0..1], [2..3, 5..6]]);
+    }
+
+    #[test]
+    fn test_box_set_canonical() {
+        // The same region, built from different boxes
+        let mut a = BoxSet::new();
+        a.insert([0..2, 0..5]);
+        a.insert([2..5, 0..5]);
+        let mut b = BoxSet::new();
+        b.insert([0..5, 0..3]);
+        b.insert([0..5, 3..5]);
+        assert_eq!(a, b);
+        assert_eq!(a.boxes(), vec![[0..5, 0..5]]);
+        assert!(a.symmetric_difference(&b).is_empty());
+    }
+
+    #[test]
+    fn test_box_set_3d() {
+        let mut cube = BoxSet::from_box([0..3, 0..3, 0..3]);
+        assert_eq!(cube.volume(), 27);
+        cube.remove([1, 1, 1]);
+        assert_eq!(cube.volume(), 26);
+        assert_eq!(cube.uncovered_cells([0..3, 0..3, 0..3]), vec![[1, 1, 1]]);
+
+        let slab = BoxSet::from_box([0..=9, 0..=9, 2..=2]);
+        assert_eq!(cube.intersection(&slab).volume(), 9);
+        assert_eq!(cube.union(&slab).volume(), 26 + 100 - 9);
+        assert_eq!(cube.difference(&slab).volume(), 26 - 9);
+
+        let collected = (0..3).flat_map(|x| (0..3).flat_map(move |y| (0..3).map(move |z| [x, y, z])))
+            .filter(|&cell| cell != [1, 1, 1])
+            .collect::<BoxSet<_, 3>>();
+        assert_eq!(collected, cube);
+    }
+
+    #[test]
+    fn test_uncovered_cells() {
+        let mut set = BoxSet::new();
+        set.insert([0..2, 0..3]);
+        set.insert([2..3, 1..3]);
+        assert_eq!(set.uncovered_cells([0..=3, 0..=2]), vec![[2, 0], [3, 0], [3, 1], [3, 2]]);
+    }
 }
@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+use anyhow::{anyhow, bail};
+
+//
+// Puzzle parameters.
+//
+// Some puzzles have numbers in the puzzle text that differ between
+// the example and the real input (like which row to examine in day 15,
+// or the size of the cube in day 22).  A day declares those as `Param`s,
+// with the value for the real input as the default.  The solver gets
+// them from a `Params`, which has the defaults, plus any values given
+// for this run.
+//
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    // Just the defaults.
+    pub fn defaults(declared: &[Param]) -> Self {
+        let values = declared.iter()
+            .map(|param| (param.name.to_string(), param.default.to_string()))
+            .collect();
+        Params { values }
+    }
+
+    //
+    // The defaults, replaced by `values` (name, value).  It is an
+    // error to give a value for a parameter that wasn't declared.
+    //
+    pub fn new<N, V>(declared: &[Param], values: impl IntoIterator<Item = (N, V)>) -> anyhow::Result<Self>
+    where N: AsRef<str>, V: Into<String>
+    {
+        let mut params = Self::defaults(declared);
+        for (name, value) in values {
+            let name = name.as_ref();
+            match params.values.get_mut(name) {
+                Some(old) => *old = value.into(),
+                None if declared.is_empty() => bail!("there are no parameters; got {name:?}"),
+                None => {
+                    let names = declared.iter().map(|param| param.name).collect::<Vec<_>>();
+                    bail!("unknown parameter {name:?}; expected one of: {}", names.join(", "));
+                }
+            }
+        }
+        Ok(params)
+    }
+
+    pub fn get<T>(&self, name: &str) -> anyhow::Result<T>
+    where T: FromStr, T::Err: Display
+    {
+        let value = self.values.get(name)
+            .ok_or_else(|| anyhow!("no parameter named {name:?}"))?;
+        value.parse().map_err(|error| anyhow!("parameter {name}={value}: {error}"))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[
+        Param { name: "row", default: "2000000", help: "The row to examine" },
+        Param { name: "bound", default: "4000000", help: "The largest coordinate" },
+    ];
+
+    #[test]
+    fn test_defaults() {
+        let params = Params::defaults(DECLARED);
+        assert_eq!(params.get::<i32>("row").unwrap(), 2_000_000);
+        assert_eq!(params.get::<i32>("bound").unwrap(), 4_000_000);
+        assert!(params.get::<i32>("column").is_err());
+    }
+
+    #[test]
+    fn test_values() {
+        let params = Params::new(DECLARED, [("row", "10")]).unwrap();
+        assert_eq!(params.get::<i32>("row").unwrap(), 10);
+        assert_eq!(params.get::<i32>("bound").unwrap(), 4_000_000);
+
+        let error = Params::new(DECLARED, [("column", "10")]).unwrap_err();
+        assert_eq!(error.to_string(), "unknown parameter \"column\"; expected one of: row, bound");
+
+        let params = Params::new(DECLARED, [("row", "ten")]).unwrap();
+        let error = params.get::<i32>("row").unwrap_err();
+        assert_eq!(error.to_string(), "parameter row=ten: invalid digit found in string");
+    }
+}
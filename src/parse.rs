@@ -0,0 +1,190 @@
+use std::{fmt::Display, str::FromStr};
+
+//
+// Errors from parsing puzzle input.
+//
+// A `ParseError` says where the problem is (line and column, both
+// starting at 1), the text that couldn't be parsed, and what was
+// expected instead.  It displays like:
+//
+//      line 3, column 12: expected a number: "twelve"
+//
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError { line, column, text: text.to_string(), message: message.into() }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: {}: {:?}", self.line, self.column, self.message, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+//
+// One line of puzzle input, and its line number.  Its methods are
+// like the ones on `str`, except they return a `ParseError` pointing
+// at the problem when they fail.
+//
+// Errors point at a piece of the line.  That piece should be a slice
+// of the line (for example, from `split` or `split_once`), so that
+// the column can be figured out from where the slice starts.
+//
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Line { number, text }
+    }
+
+    // The column where `piece` starts, if it is part of this line.
+    pub fn column_of(&self, piece: &str) -> usize {
+        let offset = (piece.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    pub fn error(&self, piece: &str, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(piece), piece, message)
+    }
+
+    // An error about the whole line.
+    pub fn invalid(&self, message: impl Into<String>) -> ParseError {
+        self.error(self.text, message)
+    }
+
+    // Parse `piece` (part of this line) as a number, or other `FromStr`.
+    pub fn parse<T: FromStr>(&self, piece: &str) -> Result<T, ParseError> {
+        piece.parse().map_err(|_| self.error(piece, format!("expected {}", describe::<T>())))
+    }
+
+    //
+    // Parse `piece` with a `FromStr` whose errors are themselves
+    // `ParseError`s (as if `piece` were line 1), and move the error
+    // to where `piece` is within this line.
+    //
+    pub fn parse_nested<T: FromStr<Err = ParseError>>(&self, piece: &str) -> Result<T, ParseError> {
+        piece.parse().map_err(|mut error: ParseError| {
+            error.line = self.number;
+            error.column += self.column_of(piece) - 1;
+            error
+        })
+    }
+
+    // Split `piece` (part of this line) at the first `separator`.
+    pub fn split_piece(&self, piece: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        piece.split_once(separator)
+            .ok_or_else(|| self.error(piece, format!("expected {separator:?}")))
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text.split_once(separator)
+            .ok_or_else(|| self.invalid(format!("expected {separator:?}")))
+    }
+
+    // The rest of the line after `prefix`.
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text.strip_prefix(prefix)
+            .ok_or_else(|| self.invalid(format!("expected {prefix:?} at the start")))
+    }
+
+    //
+    // Split the line into `N` fields, separated by `separator`.
+    // It is an error to have more or fewer fields.
+    //
+    pub fn fields<const N: usize>(&self, separator: &str) -> Result<[&'a str; N], ParseError> {
+        let fields: Vec<&str> = self.text.split(separator).collect();
+        fields.try_into().map_err(|fields: Vec<&str>| {
+            self.invalid(format!("expected {N} fields separated by {separator:?}, found {}", fields.len()))
+        })
+    }
+}
+
+// The lines of the input, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line::new(i + 1, text))
+}
+
+// An error about input that ended before it should have.
+pub fn truncated(input: &str, expected: impl Into<String>) -> ParseError {
+    let message = format!("input ended early; expected {}", expected.into());
+    ParseError::new(input.lines().count() + 1, 1, "", message)
+}
+
+// A short description of a type, for error messages.
+fn describe<T>() -> String {
+    let name = std::any::type_name::<T>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+    match name {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "a non-negative number".to_string(),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => "a number".to_string(),
+        _ => format!("a {name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_errors() {
+        let line = Line::new(3, "move 1 from two to 3");
+        let words: Vec<&str> = line.text.split(' ').collect();
+        assert_eq!(line.parse::<u32>(words[1]), Ok(1));
+        let error = line.parse::<u32>(words[3]).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 13, "two"));
+        assert_eq!(error.to_string(), "line 3, column 13: expected a non-negative number: \"two\"");
+    }
+
+    #[test]
+    fn test_fields() {
+        let line = Line::new(1, "1,2,3");
+        assert_eq!(line.fields::<3>(","), Ok(["1", "2", "3"]));
+        assert!(line.fields::<2>(",").is_err());
+        assert_eq!(line.split_once("-").unwrap_err().message, "expected \"-\"");
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        #[derive(Debug)]
+        struct Pair(u32, u32);
+        impl FromStr for Pair {
+            type Err = ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let line = Line::new(1, s);
+                let (a, b) = line.split_once("-")?;
+                Ok(Pair(line.parse(a)?, line.parse(b)?))
+            }
+        }
+
+        let line = Line::new(7, "1-2,3-x");
+        let (first, second) = line.split_once(",").unwrap();
+        assert_eq!(line.parse_nested::<Pair>(first).map(|pair| (pair.0, pair.1)), Ok((1, 2)));
+        let error = line.parse_nested::<Pair>(second).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (7, 7, "x"));
+    }
+
+    #[test]
+    fn test_truncated() {
+        let error = truncated("a\nb\n", "a blank line");
+        assert_eq!(error.line, 3);
+        assert_eq!(error.to_string(), "line 3, column 1: input ended early; expected a blank line: \"\"");
+    }
+}
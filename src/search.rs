@@ -0,0 +1,127 @@
+use std::{cell::{Cell, RefCell}, collections::{hash_map::RandomState, HashSet}, hash::{BuildHasher, Hash}, time::Instant};
+use log::{debug, log_enabled, trace, Level};
+
+//
+// Counters for the searches the slow days do (with the `pathfinding`
+// crate, or by hand), so that ideas for pruning can be measured:
+//
+//      RUST_LOG=debug aoc run 19
+//      RUST_LOG=aoc2022::days::day16=trace aoc run 16
+//
+// At debug level, each search logs a summary when it is done.  At trace
+// level, it also logs the counters every `PROGRESS_INTERVAL` states, and
+// each cache hit.
+//
+// The counters are:
+//      pushed      states generated as successors
+//      popped      states expanded (their successors generated)
+//      pruned      states (or moves) thrown away, by the reason given
+//      cache hits  states that had already been reached
+//
+// The searches in `pathfinding` don't say when they skip a state they
+// have already seen, so `push_state` keeps a set of (hashes of) the
+// states pushed, and counts the repeats.  That costs time and memory,
+// so it is only done when the summary will be logged.
+//
+// The counters are `Cell`s, so the closures passed to a search can share
+// them.  Searches that run in parallel each need their own.
+//
+pub struct SearchStats {
+    target: &'static str,
+    name: String,
+    enabled: bool,
+    start: Instant,
+    pushed: Cell<u64>,
+    popped: Cell<u64>,
+    pruned: RefCell<Vec<(&'static str, u64)>>,
+    cache_hits: Cell<u64>,
+    seen: RefCell<HashSet<u64>>,
+    hasher: RandomState,
+}
+
+const PROGRESS_INTERVAL: u64 = 1_000_000;
+
+impl SearchStats {
+    // `target` is the module to log as; pass `module_path!()`
+    pub fn new(target: &'static str, name: impl Into<String>) -> Self {
+        SearchStats {
+            target,
+            name: name.into(),
+            enabled: log_enabled!(target: target, Level::Debug),
+            start: Instant::now(),
+            pushed: Cell::new(0),
+            popped: Cell::new(0),
+            pruned: RefCell::new(Vec::new()),
+            cache_hits: Cell::new(0),
+            seen: RefCell::new(HashSet::new()),
+            hasher: RandomState::new(),
+        }
+    }
+
+    pub fn pop(&self) {
+        let popped = self.popped.get() + 1;
+        self.popped.set(popped);
+        if popped.is_multiple_of(PROGRESS_INTERVAL) {
+            trace!(target: self.target, "{}: {}", self.name, self.counters());
+        }
+    }
+
+    pub fn push(&self) {
+        self.pushed.set(self.pushed.get() + 1);
+    }
+
+    // Push a state, and count it as a cache hit if it was pushed before
+    pub fn push_state(&self, state: &impl Hash) {
+        self.push();
+        if self.enabled && !self.seen.borrow_mut().insert(self.hasher.hash_one(state)) {
+            self.cache_hit();
+        }
+    }
+
+    pub fn prune(&self, reason: &'static str) {
+        let mut pruned = self.pruned.borrow_mut();
+        match pruned.iter_mut().find(|(r, _)| *r == reason) {
+            Some((_, count)) => *count += 1,
+            None => pruned.push((reason, 1)),
+        }
+    }
+
+    pub fn cache_hit(&self) {
+        self.cache_hits.set(self.cache_hits.get() + 1);
+        trace!(target: self.target, "{}: cache hit #{}", self.name, self.cache_hits.get());
+    }
+
+    pub fn pruned(&self) -> u64 {
+        self.pruned.borrow().iter().map(|(_, count)| count).sum()
+    }
+
+    fn counters(&self) -> String {
+        let mut result = format!("pushed {}, popped {}, pruned {}", self.pushed.get(), self.popped.get(), self.pruned());
+        let pruned = self.pruned.borrow();
+        if !pruned.is_empty() {
+            let reasons = pruned.iter().map(|(reason, count)| format!("{reason}: {count}")).collect::<Vec<_>>();
+            result += &format!(" ({})", reasons.join(", "));
+        }
+        result += &format!(", cache hits {}", self.cache_hits.get());
+        result
+    }
+
+    // Log the summary
+    pub fn finish(self) {
+        debug!(target: self.target, "{}: {} in {:?}", self.name, self.counters(), self.start.elapsed());
+    }
+}
+
+#[test]
+fn test_counters() {
+    let stats = SearchStats::new(module_path!(), "test");
+    stats.pop();
+    stats.push();
+    stats.push();
+    stats.prune("bound");
+    stats.prune("time");
+    stats.prune("bound");
+    stats.cache_hit();
+    assert_eq!(stats.pruned(), 3);
+    assert_eq!(stats.counters(), "pushed 2, popped 1, pruned 3 (bound: 2, time: 1), cache hits 1");
+}
@@ -0,0 +1,179 @@
+use std::{cmp::{Ordering, Reverse}, collections::BinaryHeap};
+
+//
+// The `k` largest items of a sequence, in one pass, without keeping
+// (or sorting) the rest.  Items are compared by a key, which is the
+// item itself unless given with `by_key` (as any function or closure):
+//
+//      let mut top = TopK::by_key(3, |&(_elf, calories): &(usize, u32)| calories);
+//      top.extend(totals.into_iter().enumerate());
+//      let best = top.into_sorted_vec();
+//
+// It is a min-heap of the items kept so far, so the smallest of them is
+// the one to replace when a bigger item comes along.  Adding an item is
+// O(log k).  Of items with equal keys, the ones seen first are kept.
+//
+// A `TopK` can also be collected from an iterator, but since `collect`
+// can't be told `k`, it is part of the type (the `N` parameter):
+//
+//      let top = inspections.collect::<TopK<u64, u64, 2>>();
+//
+// `N` is only for `collect`; it is 0 when `k` is given at run time, and
+// collecting into a `TopK` without giving `N` doesn't compile (rather
+// than keeping nothing).
+//
+pub struct TopK<T, K = T, const N: usize = 0, F = fn(&T) -> K> {
+    k: usize,
+    key: F,
+    heap: BinaryHeap<Reverse<Entry<T, K>>>,
+    seen: usize,
+}
+
+struct Entry<T, K> {
+    key: K,
+    index: usize,
+    item: T,
+}
+
+impl<T: Ord + Clone> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        TopK::by_key(k, T::clone)
+    }
+}
+
+impl<T, K: Ord, F: Fn(&T) -> K> TopK<T, K, 0, F> {
+    pub fn by_key(k: usize, key: F) -> Self {
+        TopK { k, key, heap: BinaryHeap::with_capacity(k), seen: 0 }
+    }
+}
+
+impl<T, K: Ord, const N: usize, F: Fn(&T) -> K> TopK<T, K, N, F> {
+    pub fn push(&mut self, item: T) {
+        let entry = Entry { key: (self.key)(&item), index: self.seen, item };
+        self.seen += 1;
+        if self.heap.len() < self.k {
+            self.heap.push(Reverse(entry));
+        } else if let Some(mut smallest) = self.heap.peek_mut() {
+            if entry.key > smallest.0.key {
+                *smallest = Reverse(entry);
+            }
+        }
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    // The number of items kept (at most `k`)
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    // The number of items pushed, including those not kept
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    // The smallest item kept; once there are `k` items, anything that
+    // isn't bigger than this won't be kept.
+    pub fn min(&self) -> Option<&T> {
+        self.heap.peek().map(|entry| &entry.0.item)
+    }
+
+    // The items kept, from largest to smallest, leaving them in place
+    pub fn sorted(&self) -> Vec<&T> {
+        let mut entries = self.heap.iter().map(|entry| &entry.0).collect::<Vec<_>>();
+        entries.sort_by(|a, b| b.cmp(a));
+        entries.into_iter().map(|entry| &entry.item).collect()
+    }
+
+    // The items kept, from largest to smallest
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorting `Reverse`s ascending puts the largest first
+        self.heap.into_sorted_vec().into_iter().map(|entry| entry.0.item).collect()
+    }
+}
+
+impl<T, K: Ord, const N: usize, F: Fn(&T) -> K> Extend<T> for TopK<T, K, N, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Ord + Clone, const N: usize> FromIterator<T> for TopK<T, T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        const { assert!(N > 0, "collecting into a TopK needs N (as in TopK<T, T, 3>)") };
+        let mut top: Self = TopK { k: N, key: T::clone, heap: BinaryHeap::with_capacity(N), seen: 0 };
+        top.extend(iter);
+        top
+    }
+}
+
+//
+// Entries are ordered by key, and then the later of two with equal keys
+// is the smaller, so it is the one replaced.
+//
+impl<T, K: Ord> Ord for Entry<T, K> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key).then(other.index.cmp(&self.index))
+    }
+}
+
+impl<T, K: Ord> PartialOrd for Entry<T, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T, K: Ord> PartialEq for Entry<T, K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T, K: Ord> Eq for Entry<T, K> {}
+
+#[test]
+fn test_top_k() {
+    let mut top = TopK::new(3);
+    top.extend([5, 1, 9, 3, 7, 2]);
+    assert_eq!(top.len(), 3);
+    assert_eq!(top.seen(), 6);
+    assert_eq!(top.min(), Some(&5));
+    assert_eq!(top.sorted(), vec![&9, &7, &5]);
+    assert_eq!(top.into_sorted_vec(), vec![9, 7, 5]);
+}
+
+#[test]
+fn test_fewer_than_k() {
+    let top = [4, 8].into_iter().collect::<TopK<i32, i32, 5>>();
+    assert_eq!(top.k(), 5);
+    assert_eq!(top.into_sorted_vec(), vec![8, 4]);
+
+    let top = TopK::<i32>::new(0);
+    assert!(top.is_empty());
+    assert_eq!(top.min(), None);
+}
+
+#[test]
+fn test_capturing_key() {
+    // The closest to a target
+    let target = 10;
+    let mut top = TopK::by_key(2, |x: &i32| std::cmp::Reverse((x - target).abs()));
+    top.extend([1, 12, 30, 9, 7]);
+    assert_eq!(top.into_sorted_vec(), vec![9, 12]);
+}
+
+#[test]
+fn test_by_key() {
+    // Ties keep the items seen first
+    let mut top = TopK::by_key(2, |&(_, value): &(char, i32)| value);
+    top.extend([('a', 1), ('b', 3), ('c', 2), ('d', 3), ('e', 3)]);
+    assert_eq!(top.into_sorted_vec(), vec![('b', 3), ('d', 3)]);
+}
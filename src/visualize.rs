@@ -0,0 +1,224 @@
+use std::{io::Write, path::PathBuf, time::{Duration, Instant}};
+use anyhow::{bail, Context};
+
+//
+// Pictures of the simulation days, a step at a time.
+//
+// A day that is a simulation implements `Solution::visualize`, which runs
+// the simulation and hands each step to an `Animation` as text, one
+// character per cell (like `Grid::render`).  The animation either plays
+// in the terminal, or writes numbered frames to a directory:
+//
+//      frames/
+//          frame-000000.txt    (or .ppm)
+//          frame-000001.txt
+//
+// Drawing a frame can be much slower than the step of the simulation,
+// so steps ask for a frame with a closure, which is only called for the
+// steps that are shown (every `every`th step).  The days draw their
+// final state with `last`, so it is always shown.
+//
+pub struct Animation {
+    output: Output,
+    every: usize,
+    steps: usize,
+    frames: usize,
+    shown_last_step: bool,
+    last_frame: Option<Instant>,
+    error: Option<anyhow::Error>,
+}
+
+enum Output {
+    Terminal { frame_time: Duration },
+    Directory { path: PathBuf, format: FrameFormat, scale: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameFormat {
+    // The text, as is
+    Text,
+    // A binary PPM image, with a square of `scale` pixels per character
+    Ppm,
+}
+
+impl Animation {
+    pub fn terminal(fps: f64) -> anyhow::Result<Self> {
+        if !(fps > 0.0 && fps.is_finite()) {
+            bail!("frames per second must be positive; got {fps}");
+        }
+        Ok(Self::new(Output::Terminal { frame_time: Duration::from_secs_f64(1.0 / fps) }))
+    }
+
+    pub fn directory(path: impl Into<PathBuf>, format: FrameFormat, scale: usize) -> anyhow::Result<Self> {
+        let path = path.into();
+        if scale == 0 {
+            bail!("the scale must be at least 1");
+        }
+        std::fs::create_dir_all(&path)
+            .with_context(|| format!("creating {}", path.display()))?;
+        Ok(Self::new(Output::Directory { path, format, scale }))
+    }
+
+    fn new(output: Output) -> Self {
+        Animation { output, every: 1, steps: 0, frames: 0, shown_last_step: false, last_frame: None, error: None }
+    }
+
+    // Only show every `every`th step
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    // One step of the simulation
+    pub fn step(&mut self, draw: impl FnOnce() -> String) {
+        self.shown_last_step = self.steps.is_multiple_of(self.every);
+        if self.shown_last_step {
+            self.frame(draw);
+        }
+        self.steps += 1;
+    }
+
+    // The final state, unless the last step was already shown
+    pub fn last(&mut self, draw: impl FnOnce() -> String) {
+        if !self.shown_last_step {
+            self.frame(draw);
+        }
+    }
+
+    //
+    // Errors are kept until `finish`, so that the simulations don't have
+    // to check after every step.
+    //
+    fn frame(&mut self, draw: impl FnOnce() -> String) {
+        if self.error.is_none() {
+            let text = draw();
+            if let Err(error) = self.show(&text) {
+                self.error = Some(error);
+            }
+        }
+    }
+
+    // The number of frames shown, or the first error.
+    pub fn finish(self) -> anyhow::Result<usize> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.frames),
+        }
+    }
+
+    fn show(&mut self, text: &str) -> anyhow::Result<()> {
+        match &self.output {
+            Output::Terminal { frame_time } => {
+                if let Some(last) = self.last_frame {
+                    std::thread::sleep(frame_time.saturating_sub(last.elapsed()));
+                }
+                self.last_frame = Some(Instant::now());
+
+                // Clear the screen, and draw from the top left
+                let mut stdout = std::io::stdout().lock();
+                write!(stdout, "\x1b[2J\x1b[H{text}")?;
+                if !text.ends_with('\n') {
+                    writeln!(stdout)?;
+                }
+                writeln!(stdout, "step {}", self.steps)?;
+                stdout.flush()?;
+            }
+            Output::Directory { path, format, scale } => {
+                let (extension, bytes) = match format {
+                    FrameFormat::Text => ("txt", text.as_bytes().to_vec()),
+                    FrameFormat::Ppm => ("ppm", ppm(text, *scale)),
+                };
+                let path = path.join(format!("frame-{:06}.{extension}", self.frames));
+                std::fs::write(&path, bytes)
+                    .with_context(|| format!("writing {}", path.display()))?;
+            }
+        }
+        self.frames += 1;
+        Ok(())
+    }
+}
+
+//
+// Draw text as a binary PPM image, with each character a square of
+// `scale` by `scale` pixels.  Short lines are padded with background.
+//
+pub fn ppm(text: &str, scale: usize) -> Vec<u8> {
+    let rows = text.lines().map(|line| line.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+    let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let (width, height) = (columns * scale, rows.len() * scale);
+
+    let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+    image.reserve(width * height * 3);
+    for row in &rows {
+        let mut line = Vec::with_capacity(width * 3);
+        for x in 0..columns {
+            let rgb = color(row.get(x).copied().unwrap_or(' '));
+            for _ in 0..scale {
+                line.extend_from_slice(&rgb);
+            }
+        }
+        for _ in 0..scale {
+            image.extend_from_slice(&line);
+        }
+    }
+    image
+}
+
+// The colors of the characters the days draw with
+fn color(ch: char) -> [u8; 3] {
+    match ch {
+        '.' | ' ' => [16, 16, 32],              // Empty space
+        '#' | '|' | '-' | '+' => [128, 128, 128], // Rock, walls
+        'o' => [224, 192, 96],                  // Sand
+        '@' | 'E' => [64, 224, 64],             // The thing that is moving
+        '>' | '<' | '^' | 'v' => [96, 160, 255],  // Blizzards, or a trail
+        '0'..='9' => [192, 224, 255],           // Several blizzards
+        _ => {
+            // Anything else gets a color of its own
+            let n = ch as u32;
+            [(n * 67 % 192 + 64) as u8, (n * 131 % 192 + 64) as u8, (n * 199 % 192 + 64) as u8]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppm() {
+        let image = ppm("#.\n#\n", 2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        let pixels = &image[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(&pixels[0..3], &color('#'));
+        assert_eq!(&pixels[6..9], &color('.'));
+        // The short second line is padded with background
+        assert_eq!(&pixels[(3 * 4 + 3) * 3..], &color(' '));
+    }
+
+    #[test]
+    fn test_every() {
+        let path = std::env::temp_dir().join(format!("aoc-frames-test-{}", std::process::id()));
+        let mut animation = Animation::directory(&path, FrameFormat::Text, 1).unwrap().every(3);
+        let mut drawn = Vec::new();
+        for i in 0..8 {
+            animation.step(|| { drawn.push(i); format!("{i}\n") });
+        }
+        animation.last(|| "done\n".to_string());
+        assert_eq!(animation.finish().unwrap(), 4);
+        assert_eq!(drawn, [0, 3, 6]);
+        assert_eq!(std::fs::read_to_string(path.join("frame-000001.txt")).unwrap(), "3\n");
+        assert_eq!(std::fs::read_to_string(path.join("frame-000003.txt")).unwrap(), "done\n");
+
+        // The last step was shown, so it isn't repeated
+        let mut animation = Animation::directory(&path, FrameFormat::Text, 1).unwrap().every(3);
+        for i in 0..7 {
+            animation.step(|| format!("{i}\n"));
+        }
+        animation.last(|| "done\n".to_string());
+        assert_eq!(animation.finish().unwrap(), 3);
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
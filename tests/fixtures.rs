@@ -0,0 +1,59 @@
+use std::path::Path;
+use aoc2022::{fixtures, solution};
+
+//
+// Run every day's solution (and its other strategies) on every fixture,
+// and compare with the expected answers.  All of the mismatches are
+// reported together.
+//
+#[test]
+fn test_fixtures() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(fixtures::DEFAULT_ROOT);
+    let fixtures = fixtures::load_all(&root).unwrap();
+    assert!(!fixtures.is_empty(), "no fixtures in {}", root.display());
+
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for fixture in &fixtures {
+        let name = format!("day{:02}/{}", fixture.day, fixture.name);
+        let Some(solution) = solution(fixture.day) else {
+            failures.push(format!("{name}: no solution for day {}", fixture.day));
+            continue;
+        };
+        let params = match fixture.params(solution.params()) {
+            Ok(params) => params,
+            Err(error) => {
+                failures.push(format!("{name}: {error:#}"));
+                continue;
+            }
+        };
+        for skipped in &fixture.skip_strategies {
+            if !solution.strategies().iter().any(|strategy| strategy.name == skipped) {
+                failures.push(format!("{name}: skips unknown strategy {skipped:?}"));
+            }
+        }
+        for part in 1..=solution.parts() {
+            let Some(expected) = fixture.expected(part) else { continue };
+            let strategies = solution.strategies_for(part).into_iter()
+                .filter(|strategy| !strategy.slow && !fixture.skip_strategies.iter().any(|name| name == strategy.name))
+                .map(|strategy| Some(strategy.name));
+            for strategy in std::iter::once(None).chain(strategies) {
+                checked += 1;
+                let (label, result) = match strategy {
+                    None => (format!("{name} part {part}"), solution.solve(part, &fixture.input, &params)),
+                    Some(strategy) => (
+                        format!("{name} part {part} ({strategy})"),
+                        solution.solve_with(part, strategy, &fixture.input, &params)
+                    ),
+                };
+                match result {
+                    Ok(answer) if answer == expected => {}
+                    Ok(answer) => failures.push(format!("{label}: got {answer}, expected {expected}")),
+                    Err(error) => failures.push(format!("{label}: {error:#}")),
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{} of {checked} fixture answers failed:\n{}", failures.len(), failures.join("\n"));
+}
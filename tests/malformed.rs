@@ -0,0 +1,66 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
+use aoc2022::{fixtures, solution, Params};
+
+//
+// Bad inputs should give an error, never a panic: empty and blank input,
+// and each day's example cut short, after its first line, halfway through
+// its lines, and halfway through a line.  Every part and every strategy
+// that isn't slow is tried, and all of the panics are reported together.
+//
+#[test]
+fn test_malformed_inputs() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join(fixtures::DEFAULT_ROOT);
+    let fixtures = fixtures::load_all(&root).unwrap();
+
+    // Keep the panic messages for the report, rather than printing them
+    std::panic::set_hook(Box::new(|_| {}));
+
+    let mut panics = Vec::new();
+    for fixture in fixtures.iter().filter(|fixture| fixture.name == "example") {
+        let solution = solution(fixture.day).unwrap();
+        let params = Params::defaults(solution.params());
+        for (what, input) in cut_short(&fixture.input) {
+            for part in 1..=solution.parts() {
+                let strategies = solution.strategies_for(part).into_iter()
+                    .filter(|strategy| !strategy.slow)
+                    .map(|strategy| Some(strategy.name));
+                for strategy in std::iter::once(None).chain(strategies) {
+                    let result = catch_unwind(AssertUnwindSafe(|| match strategy {
+                        None => solution.solve(part, &input, &params),
+                        Some(name) => solution.solve_with(part, name, &input, &params),
+                    }));
+                    if let Err(payload) = result {
+                        let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_default();
+                        let label = match strategy {
+                            Some(name) => format!("part {part} ({name})"),
+                            None => format!("part {part}"),
+                        };
+                        panics.push(format!("day {} {label}, {what}: {message}", fixture.day));
+                    }
+                }
+            }
+        }
+    }
+
+    let _ = std::panic::take_hook();
+    assert!(panics.is_empty(), "{} panics:\n{}", panics.len(), panics.join("\n"));
+}
+
+fn cut_short(example: &str) -> Vec<(&'static str, String)> {
+    let lines = example.lines().collect::<Vec<_>>();
+    let first_lines = |n: usize| lines[..n].iter().map(|line| format!("{line}\n")).collect::<String>();
+    let mut middle = example.len() / 2;
+    while !example.is_char_boundary(middle) {
+        middle -= 1;
+    }
+    vec![
+        ("empty", String::new()),
+        ("blank", "\n".to_string()),
+        ("first line", first_lines(1.min(lines.len()))),
+        ("first half of the lines", first_lines(lines.len() / 2)),
+        ("cut mid-line", example[..middle].to_string()),
+    ]
+}
@@ -0,0 +1,224 @@
+use std::{fmt, panic::{catch_unwind, AssertUnwindSafe}};
+use aoc2022::{solution, solutions, Params, Rng, Solution, Strategy};
+
+//
+// Property tests: every strategy for a part must give the same answer, on
+// random inputs (see `aoc2022::generate`) as well as on the fixtures.
+// This is what lets the fast strategies be trusted on inputs other than
+// ours.  Slow strategies are included, with parameters that make them
+// quick enough (a few thousand rocks for day 17, rather than a trillion).
+//
+// A disagreement is shrunk before it is reported.  First, smaller sizes
+// are tried with a handful of seeds, so the counterexample can be made
+// again with `aoc gen`.  Then, for days where any input that parses is
+// a valid puzzle, lines (or, for a one line input, characters) are taken
+// out for as long as the strategies still disagree.
+//
+// The number of random inputs per day can be changed with
+// AOC_PROPERTY_CASES.
+//
+struct Property {
+    day: u8,
+    // Parameters for both generating and solving
+    params: &'static [(&'static str, &'static str)],
+    // Strategies that aren't expected to agree
+    skip: &'static [&'static str],
+    max_size: usize,
+    shrink_text: bool,
+}
+
+const PROPERTIES: &[Property] = &[
+    // The brute force checks every row, so keep the search area small
+    Property { day: 15, params: &[("bound", "2000")], skip: &[], max_size: 20, shrink_text: false },
+    // Greedy isn't always optimal, which leaves nothing to compare with
+    Property { day: 16, params: &[], skip: &["greedy"], max_size: 12, shrink_text: false },
+    // Enough rocks for the cycles to repeat several times
+    Property { day: 17, params: &[("part2_rocks", "20000")], skip: &[], max_size: 40, shrink_text: true },
+    Property { day: 18, params: &[], skip: &[], max_size: 60, shrink_text: true },
+    Property { day: 21, params: &[], skip: &[], max_size: 30, shrink_text: false },
+];
+
+const DEFAULT_CASES: u64 = 20;
+const SHRINK_SEEDS: u64 = 10;
+
+struct Counterexample {
+    day: u8,
+    part: u8,
+    seed: u64,
+    size: usize,
+    params: &'static [(&'static str, &'static str)],
+    shrunk: bool,
+    input: String,
+    answers: Vec<(&'static str, Result<String, String>)>,
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let params = self.params.iter().map(|(name, value)| format!(" --param {name}={value}")).collect::<String>();
+        writeln!(f, "Day {} part {}: the strategies disagree", self.day, self.part)?;
+        write!(f, "  input: aoc gen {} --seed {} --size {}{params}", self.day, self.seed, self.size)?;
+        if self.shrunk {
+            write!(f, ", shrunk to:\n{}", self.input)?;
+        } else {
+            writeln!(f)?;
+        }
+        for (name, answer) in &self.answers {
+            match answer {
+                Ok(answer) => writeln!(f, "  {name}: {answer}")?,
+                Err(error) => writeln!(f, "  {name}: {error}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_strategies_agree() {
+    let cases = std::env::var("AOC_PROPERTY_CASES").map_or(DEFAULT_CASES, |cases| cases.parse().unwrap());
+    let mut failures = Vec::new();
+    for property in PROPERTIES {
+        let solution = solution(property.day).unwrap();
+        let params = Params::new(solution.params(), property.params.iter().copied()).unwrap();
+        for part in 1..=solution.parts() {
+            let strategies = strategies(solution, part, property);
+            if strategies.len() < 2 {
+                continue;
+            }
+            for seed in 0..cases {
+                let size = 1 + Rng::new(seed).below(property.max_size);
+                let input = solution.generate(&mut Rng::new(seed), size, &params).unwrap();
+                let answers = solve_all(&strategies, &input, &params);
+                if disagree(&answers, true) {
+                    let found = Counterexample { day: property.day, part, seed, size, params: property.params, shrunk: false, input, answers };
+                    failures.push(shrink(property, solution, &strategies, &params, found));
+                    break;
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.iter().map(|failure| failure.to_string()).collect::<Vec<_>>().join("\n"));
+}
+
+// Every day with alternate strategies should be checked
+#[test]
+fn test_properties_cover_strategies() {
+    for solution in solutions() {
+        let several = (1..=solution.parts()).any(|part| solution.strategies_for(part).len() > 1);
+        if several {
+            assert!(PROPERTIES.iter().any(|property| property.day == solution.day()), "day {} has no property", solution.day());
+        }
+    }
+}
+
+fn strategies(solution: &dyn Solution, part: u8, property: &Property) -> Vec<&'static Strategy> {
+    solution.strategies_for(part).into_iter()
+        .filter(|strategy| !property.skip.contains(&strategy.name))
+        .collect()
+}
+
+// Each strategy's answer, or its error (or panic)
+fn solve_all(strategies: &[&'static Strategy], input: &str, params: &Params) -> Vec<(&'static str, Result<String, String>)> {
+    strategies.iter().map(|strategy| {
+        let answer = match catch_unwind(AssertUnwindSafe(|| (strategy.solve)(input, params))) {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(error)) => Err(format!("{error:#}")),
+            Err(panic) => {
+                let message = panic.downcast_ref::<&str>().map(|s| s.to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(format!("panicked: {message}"))
+            }
+        };
+        (strategy.name, answer)
+    }).collect()
+}
+
+//
+// Whether the answers show a bug.  Generated inputs are valid puzzles, so
+// any error counts.  Shrunk inputs might not be, so errors there don't
+// count (which also means a shrunk input always parses).
+//
+fn disagree(answers: &[(&str, Result<String, String>)], errors_count: bool) -> bool {
+    if answers.iter().any(|(_, answer)| answer.is_err()) {
+        return errors_count;
+    }
+    answers.windows(2).any(|pair| pair[0].1 != pair[1].1)
+}
+
+fn shrink(
+    property: &Property,
+    solution: &dyn Solution,
+    strategies: &[&'static Strategy],
+    params: &Params,
+    mut found: Counterexample,
+) -> Counterexample {
+    // The smallest size that fails with any of a few seeds
+    'sizes: for size in 1..found.size {
+        for seed in 0..SHRINK_SEEDS {
+            let input = solution.generate(&mut Rng::new(seed), size, params).unwrap();
+            let answers = solve_all(strategies, &input, params);
+            if disagree(&answers, true) {
+                found = Counterexample { seed, size, input, answers, ..found };
+                break 'sizes;
+            }
+        }
+    }
+
+    if property.shrink_text && found.answers.iter().all(|(_, answer)| answer.is_ok()) {
+        let input = shrink_text(&found.input, |input| disagree(&solve_all(strategies, input, params), false));
+        if input != found.input {
+            found.answers = solve_all(strategies, &input, params);
+            found.input = input;
+            found.shrunk = true;
+        }
+    }
+    found
+}
+
+//
+// Take out as much of `input` as possible while `fails` is still true
+// (which it must be to begin with).  Chunks of lines are taken out, or
+// of characters if there is only one line, starting with big chunks and
+// halving their size when none can be taken out.
+//
+fn shrink_text(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let one_line = input.lines().count() == 1;
+    let join = |units: &[&str]| units.join(if one_line { "" } else { "\n" }) + "\n";
+    let mut units: Vec<&str> = if one_line {
+        let line = input.trim_end_matches('\n');
+        line.char_indices().map(|(i, ch)| &line[i..i + ch.len_utf8()]).collect()
+    } else {
+        input.lines().collect()
+    };
+
+    let mut chunk = units.len() / 2;
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+        while start < units.len() {
+            let end = (start + chunk).min(units.len());
+            let candidate = [&units[..start], &units[end..]].concat();
+            if !candidate.is_empty() && fails(&join(&candidate)) {
+                units = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed {
+            chunk /= 2;
+        }
+    }
+    join(&units)
+}
+
+#[test]
+fn test_shrink_text() {
+    // One line shrinks by characters
+    let fails = |input: &str| input.contains('x') && input.contains('y');
+    assert_eq!(shrink_text("abcxdefgyhij\n", fails), "xy\n");
+
+    // Several lines shrink by lines
+    let fails = |input: &str| input.lines().filter(|line| line.starts_with('#')).count() >= 2;
+    assert_eq!(shrink_text("a\n#b\nc\nd\n#e\n#f\ng\n", fails), "#e\n#f\n");
+}